@@ -0,0 +1,116 @@
+// Gazelle: a cross-platform engine for structural analysis & design.
+module Gazelle.CLI.Arguments
+
+open System
+
+type CliOptions =
+  { Command: string
+    InputFile: string option
+    OutputFile: string option
+    Format: string
+    Verbose: bool
+    Detailed: bool
+    Template: string option
+    Parameters: string option
+    OutputDir: string option
+    Progress: bool
+    Workers: int
+    CompareFile: string option
+    Results: bool
+    Tolerance: float
+    Help: bool }
+
+let defaultOptions =
+  { Command = ""
+    InputFile = None
+    OutputFile = None
+    Format = "text"
+    Verbose = false
+    Detailed = false
+    Template = None
+    Parameters = None
+    OutputDir = None
+    Progress = false
+    Workers = Environment.ProcessorCount
+    CompareFile = None
+    Results = false
+    Tolerance = 1e-6
+    Help = false }
+
+let rec parseArgs args options =
+  match args with
+  | [] -> options
+  | "--help" :: _
+  | "help" :: _ -> { options with Help = true }
+  | "--format" :: format :: tail ->
+    parseArgs tail { options with Format = format }
+  | "--output" :: file :: tail ->
+    parseArgs tail { options with OutputFile = Some file }
+  | "--verbose" :: tail -> parseArgs tail { options with Verbose = true }
+  | "--detailed" :: tail -> parseArgs tail { options with Detailed = true }
+  | "--template" :: template :: tail ->
+    parseArgs
+      tail
+      { options with
+          Template = Some template }
+  | "--params" :: paramsFile :: tail ->
+    parseArgs
+      tail
+      { options with
+          Parameters = Some paramsFile }
+  | "--output-dir" :: dir :: tail ->
+    parseArgs tail { options with OutputDir = Some dir }
+  | "--progress" :: tail -> parseArgs tail { options with Progress = true }
+  | "--results" :: tail -> parseArgs tail { options with Results = true }
+  | "--tolerance" :: tolerance :: tail ->
+    match
+      Double.TryParse(
+        tolerance,
+        Globalization.NumberStyles.Float,
+        Globalization.CultureInfo.InvariantCulture
+      )
+    with
+    | (true, t) -> parseArgs tail { options with Tolerance = t }
+    | (false, _) -> parseArgs tail options
+  | "--workers" :: workers :: tail
+  | "--jobs" :: workers :: tail ->
+    match Int32.TryParse workers with
+    | (true, n) -> parseArgs tail { options with Workers = n }
+    | (false, _) -> parseArgs tail options
+  | cmd :: tail when not (cmd.StartsWith "--") && options.Command = "" ->
+    // Handle ETABS subcommands
+    if cmd = "etabs" then
+      match tail with
+      | subCmd :: restTail ->
+        parseArgs
+          restTail
+          { options with
+              Command = $"etabs-{subCmd}" }
+      | [] -> parseArgs tail { options with Command = "etabs-help" }
+    // For commands that don't take a file argument (like 'create'), just set command
+    elif cmd = "create" || cmd = "templates" then
+      parseArgs tail { options with Command = cmd }
+    else
+      // For commands that take a file, expect next argument to be file
+      match tail with
+      | file :: restTail when not (file.StartsWith "--") ->
+        parseArgs
+          restTail
+          { options with
+              Command = cmd
+              InputFile = Some file }
+      | _ -> parseArgs tail { options with Command = cmd }
+  | file :: tail when not (file.StartsWith "--") && options.Command <> "" ->
+    // Positional files may follow options, e.g. gz diff --results a b
+    match options.InputFile, options.CompareFile with
+    | None, _ -> parseArgs tail { options with InputFile = Some file }
+    | Some _, None when options.Command = "diff" ->
+      parseArgs tail { options with CompareFile = Some file }
+    | _ -> parseArgs tail options
+  | unknownArg :: tail ->
+    // Skip unknown arguments but continue parsing
+    parseArgs tail options
+
+let parse (args: string[]) =
+  let argsList = Array.toList args
+  parseArgs argsList defaultOptions
@@ -0,0 +1,31 @@
+// Gazelle: a cross-platform engine for structural analysis & design.
+module Gazelle.CLI.Batch
+
+open System
+open System.IO
+
+/// Suffix of the per-model results written by batch-analyze.
+let resultsSuffix = ".results.json"
+
+/// Path of the results file for a model in the output directory.
+let resultsPath (outputDir: string) (modelName: string) =
+  Path.Combine(outputDir, modelName + resultsSuffix)
+
+/// Model files in a directory or matching a file pattern, excluding the
+/// results of earlier runs, which are also .json files.
+let findModels (pattern: string) : string[] =
+  let directory, searchPattern =
+    match Directory.Exists pattern with
+    | true -> pattern, "*.json"
+    | false ->
+      let directory = Path.GetDirectoryName(pattern)
+      let directory = if directory = "" then "." else directory
+      directory, Path.GetFileName(pattern)
+
+  match Directory.Exists directory with
+  | true ->
+    Directory.GetFiles(directory, searchPattern)
+    |> Array.filter (fun f ->
+      not (f.EndsWith(resultsSuffix, StringComparison.OrdinalIgnoreCase)))
+    |> Array.sort
+  | false -> [||]
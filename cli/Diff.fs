@@ -0,0 +1,92 @@
+// Gazelle: a cross-platform engine for structural analysis & design.
+module Gazelle.CLI.Diff
+
+open System.Text.Json
+
+type DiffEntry =
+  { Change: string
+    Section: string
+    Id: string
+    Detail: string }
+
+let modelSections =
+  [ "info"
+    "nodes"
+    "elements"
+    "materials"
+    "loads"
+    "constraints"
+    "groups" ]
+
+let membersOf (model: JsonElement) (section: string) =
+  match model.TryGetProperty(section) with
+  | true, s when s.ValueKind = JsonValueKind.Object ->
+    s.EnumerateObject() |> Seq.map (fun p -> p.Name, p.Value) |> Map.ofSeq
+  | _ -> Map.empty
+
+let diffModels (a: JsonElement) (b: JsonElement) : DiffEntry list =
+  [ for section in modelSections do
+      let before = membersOf a section
+      let after = membersOf b section
+
+      let ids =
+        Seq.append before.Keys after.Keys |> Seq.distinct |> Seq.sort
+
+      for id in ids do
+        match before.TryFind id, after.TryFind id with
+        | Some x, Some y when not (JsonElement.DeepEquals(x, y)) ->
+          { Change = "Modified"
+            Section = section
+            Id = id
+            Detail = $"{x.GetRawText()} -> {y.GetRawText()}" }
+        | Some x, None ->
+          { Change = "Removed"
+            Section = section
+            Id = id
+            Detail = x.GetRawText() }
+        | None, Some y ->
+          { Change = "Added"
+            Section = section
+            Id = id
+            Detail = y.GetRawText() }
+        | _ -> () ]
+
+let rec private flatten (path: string) (e: JsonElement) =
+  match e.ValueKind with
+  | JsonValueKind.Object ->
+    [ for p in e.EnumerateObject() do
+        let child = if path = "" then p.Name else $"{path}.{p.Name}"
+        yield! flatten child p.Value ]
+  | JsonValueKind.Array ->
+    [ for i, item in Seq.indexed (e.EnumerateArray()) do
+        yield! flatten $"{path}[{i}]" item ]
+  | JsonValueKind.Number -> [ path, e.GetDouble() ]
+  | _ -> []
+
+let diffResults
+  (tolerance: float)
+  (a: JsonElement)
+  (b: JsonElement)
+  : DiffEntry list =
+  let before = flatten "" a |> Map.ofList
+  let after = flatten "" b |> Map.ofList
+  let paths = Seq.append before.Keys after.Keys |> Seq.distinct |> Seq.sort
+
+  [ for path in paths do
+      match before.TryFind path, after.TryFind path with
+      | Some x, Some y when abs (y - x) > tolerance ->
+        { Change = "Changed"
+          Section = "results"
+          Id = path
+          Detail = $"{x} -> {y} (delta {y - x:G6})" }
+      | Some x, None ->
+        { Change = "Removed"
+          Section = "results"
+          Id = path
+          Detail = $"{x}" }
+      | None, Some y ->
+        { Change = "Added"
+          Section = "results"
+          Id = path
+          Detail = $"{y}" }
+      | _ -> () ]
@@ -2,44 +2,33 @@
 open System
 open System.IO
 open System.Text.Json
+open System.Text.Json.Nodes
 open System.Text.Json.Serialization
 open Spectre.Console
+open Gazelle.CLI
+open Gazelle.CLI.Arguments
+open Gazelle.CLI.Batch
+open Gazelle.CLI.Diff
+open Gazelle.CLI.Validation
 
 // Types
-type CliOptions =
-  { Command: string
-    InputFile: string option
-    OutputFile: string option
-    Format: string
-    Verbose: bool
-    Detailed: bool
-    Template: string option
-    Parameters: string option
-    OutputDir: string option
-    Progress: bool
-    Workers: int
-    Help: bool }
-
 type ModelInfo =
   { Name: string
     Version: string
     NodeCount: int
     ElementCount: int
-    LoadCases: int }
+    Loads: int
+    Groups: int }
 
 type AnalysisResult =
   { ModelName: string
     Status: string
     MaxDisplacement: float option
     MaxStress: float option
+    MaxReaction: float option
     Warnings: string[]
     Errors: string[] }
 
-type ValidationResult =
-  { IsValid: bool
-    Errors: string[]
-    Warnings: string[] }
-
 type ETABSResult =
   { Command: string
     Status: string
@@ -66,21 +55,6 @@ let serializeToFile<'T> (filePath: string) (value: 'T) =
   let json = serialize value
   File.WriteAllText(filePath, json)
 
-// Default options
-let defaultOptions =
-  { Command = ""
-    InputFile = None
-    OutputFile = None
-    Format = "text"
-    Verbose = false
-    Detailed = false
-    Template = None
-    Parameters = None
-    OutputDir = None
-    Progress = false
-    Workers = Environment.ProcessorCount
-    Help = false }
-
 // Available templates
 let templates =
   [ { Name = "beam"
@@ -145,6 +119,12 @@ let showHelp () =
   )
   |> ignore
 
+  grid.AddRow(
+    "  [green]diff[/] [cyan]<a> <b>[/]",
+    "Compare two models (or results with --results)"
+  )
+  |> ignore
+
   grid.AddEmptyRow() |> ignore
   grid.AddRow("[yellow]ETABS INTEGRATION:[/]", "") |> ignore
 
@@ -179,6 +159,18 @@ let showHelp () =
 
   grid.AddRow("  [grey]--verbose[/]", "Enable verbose output") |> ignore
 
+  grid.AddRow(
+    "  [grey]--jobs[/] [cyan]<n>[/]",
+    "Parallel workers for batch-analyze (default: CPU count)"
+  )
+  |> ignore
+
+  grid.AddRow(
+    "  [grey]--output-dir[/] [cyan]<dir>[/]",
+    "Directory for batch-analyze result files"
+  )
+  |> ignore
+
   grid.AddRow("  [grey]--quiet[/]", "Suppress all output except errors")
   |> ignore
 
@@ -196,71 +188,13 @@ let showHelp () =
   grid.AddRow("  [dim]gz create --template truss --output model.json[/]", "")
   |> ignore
 
+  grid.AddRow("  [dim]gz batch-analyze models/ --jobs 4[/]", "") |> ignore
+  grid.AddRow("  [dim]gz diff a.json b.json --results[/]", "") |> ignore
   grid.AddRow("  [dim]gz etabs demo --verbose[/]", "") |> ignore
 
   AnsiConsole.Write(grid)
   AnsiConsole.WriteLine()
 
-// Argument parsing
-let rec parseArgs args options =
-  match args with
-  | [] -> options
-  | "--help" :: _
-  | "help" :: _ -> { options with Help = true }
-  | "--format" :: format :: tail ->
-    parseArgs tail { options with Format = format }
-  | "--output" :: file :: tail ->
-    parseArgs tail { options with OutputFile = Some file }
-  | "--verbose" :: tail -> parseArgs tail { options with Verbose = true }
-  | "--detailed" :: tail -> parseArgs tail { options with Detailed = true }
-  | "--template" :: template :: tail ->
-    parseArgs
-      tail
-      { options with
-          Template = Some template }
-  | "--params" :: paramsFile :: tail ->
-    parseArgs
-      tail
-      { options with
-          Parameters = Some paramsFile }
-  | "--output-dir" :: dir :: tail ->
-    parseArgs tail { options with OutputDir = Some dir }
-  | "--progress" :: tail -> parseArgs tail { options with Progress = true }
-  | "--workers" :: workers :: tail ->
-    match Int32.TryParse workers with
-    | (true, n) -> parseArgs tail { options with Workers = n }
-    | (false, _) -> parseArgs tail options
-  | cmd :: tail when not (cmd.StartsWith "--") && options.Command = "" ->
-    // Handle ETABS subcommands
-    if cmd = "etabs" then
-      match tail with
-      | subCmd :: restTail ->
-        parseArgs
-          restTail
-          { options with
-              Command = $"etabs-{subCmd}" }
-      | [] -> parseArgs tail { options with Command = "etabs-help" }
-    // For commands that don't take a file argument (like 'create'), just set command
-    elif cmd = "create" || cmd = "templates" then
-      parseArgs tail { options with Command = cmd }
-    else
-      // For commands that take a file, expect next argument to be file
-      match tail with
-      | file :: restTail when not (file.StartsWith "--") ->
-        parseArgs
-          restTail
-          { options with
-              Command = cmd
-              InputFile = Some file }
-      | _ -> parseArgs tail { options with Command = cmd }
-  | unknownArg :: tail ->
-    // Skip unknown arguments but continue parsing
-    parseArgs tail options
-
-let parse (args: string[]) =
-  let argsList = Array.toList args
-  parseArgs argsList defaultOptions
-
 // Modern output helpers with colors
 let outputResult format content =
   match format with
@@ -286,11 +220,15 @@ let outputResult format content =
       table.AddRow("[cyan]Version[/]", model.Version) |> ignore
       table.AddRow("[cyan]Nodes[/]", model.NodeCount.ToString()) |> ignore
       table.AddRow("[cyan]Elements[/]", model.ElementCount.ToString()) |> ignore
-      table.AddRow("[cyan]Load Cases[/]", model.LoadCases.ToString()) |> ignore
+      table.AddRow("[cyan]Loads[/]", model.Loads.ToString()) |> ignore
+      table.AddRow("[cyan]Groups[/]", model.Groups.ToString()) |> ignore
     | :? AnalysisResult as result ->
       table.Title <- TableTitle("Analysis Results")
       table.AddRow("[cyan]Model[/]", result.ModelName) |> ignore
-      table.AddRow("[cyan]Status[/]", $"[green]{result.Status}[/]") |> ignore
+      let statusColor = if result.Status = "Success" then "green" else "yellow"
+
+      table.AddRow("[cyan]Status[/]", $"[{statusColor}]{result.Status}[/]")
+      |> ignore
 
       match result.MaxDisplacement with
       | Some d ->
@@ -300,6 +238,14 @@ let outputResult format content =
       match result.MaxStress with
       | Some s -> table.AddRow("[cyan]Max Stress[/]", $"{s:F1} MPa") |> ignore
       | None -> ()
+
+      match result.MaxReaction with
+      | Some r ->
+        table.AddRow("[cyan]Max Reaction[/]", $"{r:F1} kN") |> ignore
+      | None -> ()
+
+      for w in result.Warnings do
+        table.AddRow("[yellow]Warning[/]", Markup.Escape w) |> ignore
     | :? ValidationResult as validation ->
       table.Title <- TableTitle("Validation Results")
       let statusColor = if validation.IsValid then "green" else "red"
@@ -313,6 +259,12 @@ let outputResult format content =
 
       table.AddRow("[cyan]Warnings[/]", validation.Warnings.Length.ToString())
       |> ignore
+
+      for e in validation.Errors do
+        table.AddRow("[red]Error[/]", Markup.Escape e) |> ignore
+
+      for w in validation.Warnings do
+        table.AddRow("[yellow]Warning[/]", Markup.Escape w) |> ignore
     | _ ->
       let contentStr = content.ToString()
       table.AddRow("[cyan]Result[/]", contentStr) |> ignore
@@ -373,13 +325,27 @@ let infoCommand (options: CliOptions) =
     1
   | Some file ->
     try
-      // Mock implementation - replace with actual model loading
-      let modelInfo =
-        { Name = Path.GetFileNameWithoutExtension(file)
-          Version = "1.0"
-          NodeCount = 10
-          ElementCount = 8
-          LoadCases = 2 }
+      use model = JsonDocument.Parse(File.ReadAllText(file))
+      let count section = (membersOf model.RootElement section).Count
+
+      let info (name: string) =
+        match model.RootElement.TryGetProperty("info") with
+        | true, i when i.ValueKind = JsonValueKind.Object ->
+          match i.TryGetProperty(name) with
+          | true, v when v.ValueKind = JsonValueKind.String ->
+            Some(v.GetString())
+          | _ -> None
+        | _ -> None
+
+      let modelInfo: ModelInfo =
+        { Name =
+            info "name"
+            |> Option.defaultValue (Path.GetFileNameWithoutExtension(file))
+          Version = info "version" |> Option.defaultValue ""
+          NodeCount = count "nodes"
+          ElementCount = count "elements"
+          Loads = count "loads"
+          Groups = count "groups" }
 
       match options.OutputFile with
       | Some outputFile -> outputToFile options.Format outputFile modelInfo
@@ -390,6 +356,16 @@ let infoCommand (options: CliOptions) =
       showError $"Error reading model: {ex.Message}"
       1
 
+let analyzeModel (file: string) : AnalysisResult =
+  // Mock analysis - replace with actual analysis
+  { ModelName = Path.GetFileNameWithoutExtension(file)
+    Status = "Mock"
+    MaxDisplacement = Some 0.025
+    MaxStress = Some 145.2
+    MaxReaction = Some 12.5
+    Warnings = [| "No solver is available yet; results are placeholders" |]
+    Errors = [||] }
+
 let analyzeCommand (options: CliOptions) =
   match options.InputFile with
   | None ->
@@ -403,14 +379,7 @@ let analyzeCommand (options: CliOptions) =
       if options.Verbose then
         showInfo $"Analyzing model: {file}"
 
-      // Mock analysis - replace with actual analysis
-      let result =
-        { ModelName = Path.GetFileNameWithoutExtension(file)
-          Status = "Success"
-          MaxDisplacement = Some 0.025
-          MaxStress = Some 145.2
-          Warnings = [||]
-          Errors = [||] }
+      let result = analyzeModel file
 
       match options.OutputFile with
       | Some outputFile -> outputToFile options.Format outputFile result
@@ -431,11 +400,13 @@ let validateCommand (options: CliOptions) =
     1
   | Some file ->
     try
-      // Mock validation - replace with actual validation
-      let result =
-        { IsValid = true
-          Errors = [||]
-          Warnings = [||] }
+      let result: ValidationResult =
+        match JsonNode.Parse(File.ReadAllText(file)) |> Schema.migrate with
+        | Ok model -> diagnoseModel (JsonSerializer.SerializeToElement(model))
+        | Error e ->
+          { IsValid = false
+            Errors = [| e |]
+            Warnings = [||] }
 
       if options.Verbose then
         printfn "Validating model: %s" file
@@ -471,13 +442,10 @@ let createCommand (options: CliOptions) =
         if options.Verbose then
           printfn "Creating model from template: %s" templateName
 
-        // Mock model creation - replace with actual implementation
         let newModel =
-          { Name = sprintf "Generated_%s" templateName
-            Version = "1.0"
-            NodeCount = 4
-            ElementCount = 3
-            LoadCases = 1 }
+          match Templates.model tmpl.Name with
+          | Some model -> model
+          | None -> failwith $"Template '{tmpl.Name}' has no model"
 
         match options.OutputFile with
         | Some outputFile ->
@@ -526,10 +494,163 @@ let templatesCommand (options: CliOptions) =
     showError $"Error listing templates: {ex.Message}"
     1
 
+let private formatOption (value: float option) =
+  match value with
+  | Some v -> v.ToString(Globalization.CultureInfo.InvariantCulture)
+  | None -> ""
+
+let writeSummaryCsv (filePath: string) (results: AnalysisResult[]) =
+  let header =
+    "model,status,max_displacement_m,max_reaction_kn,max_stress_mpa,errors"
+
+  let rows =
+    results
+    |> Array.map (fun r ->
+      String.Join(
+        ",",
+        r.ModelName,
+        r.Status,
+        formatOption r.MaxDisplacement,
+        formatOption r.MaxReaction,
+        formatOption r.MaxStress,
+        string r.Errors.Length
+      ))
+
+  File.WriteAllLines(filePath, Array.append [| header |] rows)
+
 let batchAnalyzeCommand (options: CliOptions) =
-  // Mock implementation - would process multiple files
-  printfn "Batch analysis not yet implemented"
-  0
+  match options.InputFile with
+  | None ->
+    showError "No model directory or pattern specified"
+    1
+  | Some pattern ->
+    let files = findModels pattern
+
+    match files with
+    | [||] ->
+      showError $"No model files found: {pattern}"
+      1
+    | files ->
+      try
+        let outputDir =
+          options.OutputDir
+          |> Option.defaultValue (Path.GetDirectoryName(files[0]))
+
+        Directory.CreateDirectory(outputDir) |> ignore
+        let results = Array.zeroCreate<AnalysisResult> files.Length
+        let workers = max 1 options.Workers
+
+        let parallelOptions =
+          Threading.Tasks.ParallelOptions(MaxDegreeOfParallelism = workers)
+
+        withProgress $"Analyzing {files.Length} models..." (fun () ->
+          Threading.Tasks.Parallel.For(
+            0,
+            files.Length,
+            parallelOptions,
+            fun i ->
+              let result =
+                try
+                  analyzeModel files[i]
+                with ex ->
+                  { ModelName = Path.GetFileNameWithoutExtension(files[i])
+                    Status = "Failed"
+                    MaxDisplacement = None
+                    MaxStress = None
+                    MaxReaction = None
+                    Warnings = [||]
+                    Errors = [| ex.Message |] }
+
+              let resultsFile = resultsPath outputDir result.ModelName
+
+              serializeToFile resultsFile result
+              results[i] <- result
+          )
+          |> ignore)
+
+        let summaryFile =
+          options.OutputFile
+          |> Option.defaultValue (Path.Combine(outputDir, "summary.csv"))
+
+        writeSummaryCsv summaryFile results
+
+        let failed = results |> Array.filter (fun r -> r.Errors.Length > 0)
+
+        if options.Verbose then
+          for r in results do
+            showInfo $"{r.ModelName}: {r.Status}"
+
+        let analyzed = files.Length - failed.Length
+        showSuccess $"Analyzed {analyzed}/{files.Length} models"
+        showSuccess $"Summary written to [cyan]{summaryFile}[/]"
+
+        if results |> Array.exists (fun r -> r.Status = "Mock") then
+          showWarning "No solver is available yet; results are placeholders"
+
+        if failed.Length = 0 then 0 else 1
+      with ex ->
+        showError $"Error during batch analysis: {ex.Message}"
+        1
+
+let diffCommand (options: CliOptions) =
+  match options.InputFile, options.CompareFile with
+  | None, _
+  | _, None ->
+    showError "Two files are required: gz diff <a> <b>"
+    1
+  | Some a, _ when not (File.Exists a) ->
+    showError $"File not found: {a}"
+    1
+  | _, Some b when not (File.Exists b) ->
+    showError $"File not found: {b}"
+    1
+  | Some a, Some b ->
+    try
+      use before = JsonDocument.Parse(File.ReadAllText a)
+      use after = JsonDocument.Parse(File.ReadAllText b)
+
+      let entries =
+        match options.Results with
+        | true ->
+          diffResults options.Tolerance before.RootElement after.RootElement
+        | false -> diffModels before.RootElement after.RootElement
+
+      match options.OutputFile, options.Format with
+      | Some outputFile, _ -> outputToFile options.Format outputFile entries
+      | None, "json" -> outputResult options.Format entries
+      | None, _ when entries.IsEmpty -> showSuccess "No differences found"
+      | None, _ ->
+        let table = Table()
+        table.AddColumn("Change") |> ignore
+        table.AddColumn("Section") |> ignore
+        table.AddColumn("Id") |> ignore
+        table.AddColumn("Detail") |> ignore
+        table.Border <- TableBorder.Rounded
+        table.BorderStyle <- Style.Parse("blue")
+        let title = $"{Path.GetFileName a} vs {Path.GetFileName b}"
+        table.Title <- TableTitle(title)
+
+        for entry in entries do
+          let colour =
+            match entry.Change with
+            | "Added" -> "green"
+            | "Removed" -> "red"
+            | _ -> "yellow"
+
+          table.AddRow(
+            $"[{colour}]{entry.Change}[/]",
+            entry.Section,
+            Markup.Escape entry.Id,
+            Markup.Escape entry.Detail
+          )
+          |> ignore
+
+        AnsiConsole.Write(table)
+
+      if entries.IsEmpty then 0 else 1
+    with ex ->
+      showError $"Error comparing files: {ex.Message}"
+      1
 
 // ETABS Commands
 let etabsDemoCommand (options: CliOptions) =
@@ -699,6 +820,7 @@ let executeCommand (options: CliOptions) =
   | "create" -> createCommand options
   | "templates" -> templatesCommand options
   | "batch-analyze" -> batchAnalyzeCommand options
+  | "diff" -> diffCommand options
   // ETABS Commands
   | "etabs-demo" -> etabsDemoCommand options
   | "etabs-units" -> etabsUnitsCommand options
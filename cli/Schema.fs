@@ -0,0 +1,69 @@
+// Gazelle: a cross-platform engine for structural analysis & design.
+module Gazelle.CLI.Schema
+
+open System
+open System.Text.Json
+open System.Text.Json.Nodes
+open Gazelle.CLI.Diff
+
+/// Version of the model schema written to schema_version.
+let currentVersion = 1
+
+/// Location of the model JSON schema, written to $schema.
+let schemaUrl =
+  "https://github.com/jsbayley/gazelle/schemas/model-schema.json"
+
+let private metadata = [ "$schema"; "schema_version" ]
+
+/// Upgrades applied in order to reach each schema version. Models saved
+/// before schema_version existed are treated as version 0, which has the
+/// same structure as version 1.
+let private migrations: (int * (JsonObject -> unit)) list = [ 1, ignore ]
+
+/// Reads the schema version of a model, or 0 if it has none.
+let version (model: JsonObject) : Result<int, string> =
+  let invalid = "schema_version must be a positive integer"
+
+  match model["schema_version"] with
+  | null -> Ok 0
+  | v when v.GetValueKind() = JsonValueKind.Number ->
+    match v.AsValue().TryGetValue<int>() with
+    | true, n when n >= 1 -> Ok n
+    | _ -> Error invalid
+  | _ -> Error invalid
+
+/// Upgrades a model to the current schema version in place.
+let migrate (model: JsonNode) : Result<JsonObject, string> =
+  match model with
+  | :? JsonObject as model ->
+    match version model with
+    | Error e -> Error e
+    | Ok v when v > currentVersion ->
+      Error $"Schema version {v} is newer than supported ({currentVersion})"
+    | Ok v ->
+      for target, upgrade in migrations do
+        if target > v then
+          upgrade model
+          model["schema_version"] <- JsonValue.Create(target)
+
+      Ok model
+  | _ -> Error "Model must be a JSON object"
+
+/// Checks the info.version field and reports unknown top-level sections.
+let check (model: JsonElement) : string list =
+  let version =
+    match model.TryGetProperty("info") with
+    | true, info when info.ValueKind = JsonValueKind.Object ->
+      match info.TryGetProperty("version") with
+      | true, v when v.ValueKind = JsonValueKind.String -> Some(v.GetString())
+      | _ -> None
+    | _ -> None
+
+  [ match version |> Option.map (fun v -> v, Version.TryParse v) with
+    | None -> "Model has no info.version"
+    | Some(v, (false, _)) -> $"Model version {v} is not of the form major.minor"
+    | Some _ -> ()
+
+    for p in model.EnumerateObject() do
+      if not (List.contains p.Name (metadata @ modelSections)) then
+        $"Unknown model section: {p.Name}" ]
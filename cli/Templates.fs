@@ -0,0 +1,118 @@
+// Gazelle: a cross-platform engine for structural analysis & design.
+module Gazelle.CLI.Templates
+
+open System.Text.Json
+open System.Text.Json.Nodes
+
+let private node (id: string) (x: float) (z: float) =
+  id, {| id = id; x = x; y = 0.0; z = z |}
+
+let private element (id: string) (kind: string) (a: string) (b: string) =
+  id,
+  {| id = id
+     ``type`` = kind
+     nodes = [| a; b |]
+     material = "steel" |}
+
+let private support
+  (id: string)
+  (kind: string)
+  (node: string)
+  (dof: string list)
+  =
+  id,
+  {| id = id
+     ``type`` = kind
+     node = node
+     dof = Array.ofList dof |}
+
+let private force
+  (id: string)
+  (node: string)
+  (direction: string)
+  (magnitude: float)
+  =
+  id,
+  {| id = id
+     ``type`` = "Force"
+     node = node
+     direction = direction
+     magnitude = magnitude |}
+
+let private steel =
+  "steel",
+  {| id = "steel"
+     name = "S355"
+     ``type`` = "Steel"
+     elastic_modulus = 210e9
+     density = 7850.0
+     yield_strength = 355e6 |}
+
+let private pinned = [ "Ux"; "Uy"; "Uz" ]
+let private roller = [ "Uy"; "Uz" ]
+let private fixedDofs = [ "Ux"; "Uy"; "Uz"; "Rx"; "Ry"; "Rz" ]
+
+let private create name description nodes elements constraints loads =
+  let section items : JsonNode =
+    JsonSerializer.SerializeToNode(Map.ofList items)
+
+  let model = JsonObject()
+  model["$schema"] <- JsonValue.Create(Schema.schemaUrl)
+  model["schema_version"] <- JsonValue.Create(Schema.currentVersion)
+
+  model["info"] <-
+    JsonSerializer.SerializeToNode(
+      {| name = name
+         description = description
+         units = "SI"
+         version = "1.0" |}
+    )
+
+  model["nodes"] <- section nodes
+  model["elements"] <- section elements
+  model["materials"] <- section [ steel ]
+  model["loads"] <- section loads
+  model["constraints"] <- section constraints
+  model
+
+/// Schema-conformant starting model for a template, in N and m.
+let model (template: string) : JsonObject option =
+  match template with
+  | "beam" ->
+    create
+      "Generated_beam"
+      "Simply supported beam with a midspan point load"
+      [ node "n1" 0.0 0.0; node "n2" 3.0 0.0; node "n3" 6.0 0.0 ]
+      [ element "e1" "Beam" "n1" "n2"; element "e2" "Beam" "n2" "n3" ]
+      [ support "c1" "Pinned" "n1" pinned
+        support "c2" "Roller" "n3" roller ]
+      [ force "l1" "n2" "Fz" (-10_000.0) ]
+    |> Some
+  | "truss" ->
+    create
+      "Generated_truss"
+      "Triangular truss with an apex point load"
+      [ node "n1" 0.0 0.0; node "n2" 4.0 0.0; node "n3" 2.0 3.0 ]
+      [ element "e1" "Truss2D" "n1" "n2"
+        element "e2" "Truss2D" "n2" "n3"
+        element "e3" "Truss2D" "n1" "n3" ]
+      [ support "c1" "Pinned" "n1" pinned
+        support "c2" "Roller" "n2" roller ]
+      [ force "l1" "n3" "Fz" (-10_000.0) ]
+    |> Some
+  | "frame" ->
+    create
+      "Generated_frame"
+      "Fixed-base portal frame with a lateral point load"
+      [ node "n1" 0.0 0.0
+        node "n2" 0.0 4.0
+        node "n3" 6.0 4.0
+        node "n4" 6.0 0.0 ]
+      [ element "e1" "Frame2D" "n1" "n2"
+        element "e2" "Frame2D" "n2" "n3"
+        element "e3" "Frame2D" "n3" "n4" ]
+      [ support "c1" "Fixed" "n1" fixedDofs
+        support "c2" "Fixed" "n4" fixedDofs ]
+      [ force "l1" "n2" "Fx" 5_000.0 ]
+    |> Some
+  | _ -> None
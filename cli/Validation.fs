@@ -0,0 +1,268 @@
+// Gazelle: a cross-platform engine for structural analysis & design.
+module Gazelle.CLI.Validation
+
+open System
+open System.Text.Json
+open Gazelle.CLI.Diff
+
+type ValidationResult =
+  { IsValid: bool
+    Errors: string[]
+    Warnings: string[] }
+
+let private nodesOf (element: JsonElement) =
+  match element.TryGetProperty("nodes") with
+  | true, n when n.ValueKind = JsonValueKind.Array ->
+    List.ofSeq (n.EnumerateArray())
+  | _ -> []
+
+let private isString (x: JsonElement) = x.ValueKind = JsonValueKind.String
+
+let private dofNames = [| "Ux"; "Uy"; "Uz"; "Rx"; "Ry"; "Rz" |]
+
+let private kindOf (element: JsonElement) =
+  match element.TryGetProperty("type") with
+  | true, t when isString t -> t.GetString()
+  | _ -> ""
+
+let private strings (array: JsonElement) =
+  match array.ValueKind with
+  | JsonValueKind.Array ->
+    [ for x in array.EnumerateArray() do
+        if isString x then
+          x.GetString() ]
+  | _ -> []
+
+let private coordinates (node: JsonElement) =
+  let axis (name: string) =
+    match node.TryGetProperty(name) with
+    | true, v when v.ValueKind = JsonValueKind.Number -> v.GetDouble()
+    | _ -> 0.0
+
+  [| axis "x"; axis "y"; axis "z" |]
+
+// Models whose nodes share a y, z or x coordinate move in that plane. The
+// xz plane is tried first, as beams along x are loaded in z.
+let private activeDofs (points: float[] seq) =
+  let flat axis =
+    let values = points |> Seq.map (fun p -> p[axis])
+    Seq.max values - Seq.min values <= 1e-9
+
+  if flat 1 then [ 0; 2; 4 ]
+  elif flat 2 then [ 0; 1; 5 ]
+  elif flat 0 then [ 1; 2; 3 ]
+  else [ 0..5 ]
+
+// Rows of the compatibility matrix, as (node, dof) coefficients, which are
+// zero under any rigid-body motion of the two nodes. A truss bar only
+// stretches; other elements are rigidly jointed and also bend and twist.
+let private deformations axial (a, pa: float[]) (b, pb: float[]) =
+  let l = Array.map2 (-) pb pa
+  let length = sqrt (Array.sumBy (fun x -> x * x) l)
+
+  if axial then
+    if length = 0.0 then
+      []
+    else
+      [ [ for i in 0..2 do
+            (a, i), -l[i] / length
+            (b, i), l[i] / length ] ]
+  else
+    // u_b - u_a - θ_a × L and θ_b - θ_a
+    [ for i in 0..2 do
+        let j, k = (i + 1) % 3, (i + 2) % 3
+        [ (b, i), 1.0; (a, i), -1.0; (a, 3 + j), -l[k]; (a, 3 + k), l[j] ]
+
+      for i in 3..5 do
+        [ (b, i), 1.0; (a, i), -1.0 ] ]
+
+// Basis of the null space by Gauss-Jordan elimination with partial
+// pivoting. Each vector is a mode that moves without straining an element.
+let private nullSpace (rows: float[][]) (n: int) : float[] list =
+  let a = rows |> Array.map Array.copy
+  let scale = a |> Seq.collect id |> Seq.fold (fun m x -> max m (abs x)) 1.0
+  let pivots = Collections.Generic.Dictionary<int, int>()
+
+  for c in 0 .. n - 1 do
+    let r = pivots.Count
+
+    if r < a.Length then
+      let p = [ r .. a.Length - 1 ] |> List.maxBy (fun i -> abs (a[i][c]))
+
+      if abs (a[p][c]) > 1e-9 * scale then
+        let top = a[p]
+        a[p] <- a[r]
+        a[r] <- top |> Array.map (fun x -> x / top[c])
+
+        for i in 0 .. a.Length - 1 do
+          let f = a[i][c]
+
+          if i <> r && f <> 0.0 then
+            a[i] <- Array.map2 (fun x y -> x - f * y) a[i] a[r]
+
+        pivots[c] <- r
+
+  [ for c in 0 .. n - 1 do
+      if not (pivots.ContainsKey c) then
+        let v: float[] = Array.zeroCreate n
+        v[c] <- 1.0
+
+        for KeyValue(pc, pr) in pivots do
+          v[pc] <- -a[pr][c]
+
+        v ]
+
+let diagnoseModel (model: JsonElement) : ValidationResult =
+  let nodes = membersOf model "nodes"
+  let references = membersOf model "elements" |> Map.map (fun _ e -> nodesOf e)
+  let kinds = membersOf model "elements" |> Map.map (fun _ e -> kindOf e)
+
+  let malformed =
+    [ for KeyValue(id, ns) in references do
+        for n in ns do
+          if not (isString n) then
+            let value = n.GetRawText()
+            $"Element {id} has a node reference that is not a string: {value}" ]
+
+  let elements =
+    references
+    |> Map.map (fun _ ns ->
+      ns |> List.filter isString |> List.map (fun n -> n.GetString()))
+
+  let connected = elements.Values |> Seq.concat |> Set.ofSeq
+
+  let missing =
+    [ for KeyValue(id, ns) in elements do
+        for n in ns do
+          if not (nodes.ContainsKey n) then
+            $"Element {id} references missing node {n}" ]
+
+  let free =
+    [ for id in nodes.Keys do
+        if not (connected.Contains id) then
+          $"Node {id} is not connected to any element" ]
+
+  let neighbours =
+    [ for ns in elements.Values do
+        let ns = ns |> List.filter nodes.ContainsKey
+
+        for a in ns do
+          for b in ns do
+            if a <> b then
+              a, b ]
+    |> List.groupBy fst
+    |> List.map (fun (n, pairs) -> n, List.map snd pairs)
+    |> Map.ofList
+
+  // Walks along elements to collect the nodes of each connected part.
+  let rec walk (seen: Set<string>) (stack: string list) =
+    match stack with
+    | [] -> seen
+    | n :: rest when seen.Contains n -> walk seen rest
+    | n :: rest ->
+      let next = neighbours.TryFind n |> Option.defaultValue []
+      walk (seen.Add n) (next @ rest)
+
+  let parts =
+    nodes.Keys
+    |> Seq.filter connected.Contains
+    |> Seq.fold
+      (fun parts n ->
+        match parts |> List.exists (Set.contains n) with
+        | true -> parts
+        | false -> walk Set.empty [ n ] :: parts)
+      []
+    |> List.sortByDescending Set.count
+
+  let islands =
+    match parts with
+    | []
+    | [ _ ] -> []
+    | _ :: rest ->
+      [ for part in rest do
+          let ids = String.Join(", ", part)
+          $"Nodes {ids} are disconnected from the rest of the model" ]
+
+  let restrained =
+    [ for KeyValue(_, c) in membersOf model "constraints" do
+        match c.TryGetProperty("node"), c.TryGetProperty("dof") with
+        | (true, n), (true, dofs) when isString n ->
+          for d in strings dofs do
+            match Array.IndexOf(dofNames, d) with
+            | -1 -> ()
+            | i -> n.GetString(), i
+        | _ -> () ]
+    |> Set.ofList
+
+  let mechanisms =
+    let point id = coordinates nodes[id]
+    let points = connected |> Seq.filter nodes.ContainsKey |> Seq.map point
+
+    let members =
+      [ for KeyValue(id, ns) in elements do
+          let axial = kinds[id] = "Truss2D"
+          let ns = ns |> List.filter nodes.ContainsKey
+
+          for a, b in List.pairwise ns do
+            axial, a, b ]
+
+    let jointed =
+      members
+      |> List.collect (fun (axial, a, b) -> if axial then [] else [ a; b ])
+      |> Set.ofList
+
+    let active = if Seq.isEmpty points then [] else activeDofs points
+
+    // Restrained DOFs cannot move, so they are left out of the matrix.
+    let movable (n, d) =
+      (d < 3 || jointed.Contains n) && not (restrained.Contains((n, d)))
+
+    let columns =
+      [| for part in parts do
+           for n in part do
+             for d in active do
+               if movable (n, d) then
+                 n, d |]
+
+    let index = columns |> Array.mapi (fun i c -> c, i) |> dict
+
+    let matrix =
+      [| for axial, a, b in members do
+           for row in deformations axial (a, point a) (b, point b) do
+             let r: float[] = Array.zeroCreate columns.Length
+
+             for dof, x in row do
+               match index.TryGetValue dof with
+               | true, i -> r[i] <- r[i] + x
+               | _ -> ()
+
+             r |]
+
+    let moving =
+      nullSpace matrix columns.Length
+      |> List.collect (fun v ->
+        let largest = v |> Array.map abs |> Array.max
+
+        [ for i in 0 .. v.Length - 1 do
+            if abs (v[i]) > 1e-6 * largest then
+              i ])
+      |> Set.ofList
+
+    [ for part in parts do
+        let dofs =
+          [ for i in 0 .. columns.Length - 1 do
+              let n, d = columns[i]
+
+              if moving.Contains i && part.Contains n then
+                $"{n} {dofNames[d]}" ]
+
+        if not dofs.IsEmpty then
+          let ids = String.Join(", ", part)
+          let names = String.Join(", ", dofs)
+          $"Nodes {ids} form a mechanism free to move in {names}" ]
+
+  let errors = Schema.check model @ malformed @ missing @ mechanisms
+
+  { IsValid = errors.IsEmpty
+    Errors = Array.ofList errors
+    Warnings = Array.ofList (free @ islands) }
@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Geometry
+
+open Gazelle.Units
+
+/// <summary>
+/// Geometric properties of a cross-section. Y-Y is the major axis and
+/// Z-Z is the minor axis.
+/// </summary>
+type SectionProperties =
+  { Designation: string
+    Area: float<mm^2>
+    SecondMomentY: float<mm^4>
+    SecondMomentZ: float<mm^4>
+    TorsionConstant: float<mm^4>
+    ElasticModulusY: float<mm^3>
+    ElasticModulusZ: float<mm^3>
+    PlasticModulusY: float<mm^3>
+    PlasticModulusZ: float<mm^3> }
+
+/// <summary>
+/// Parametric cross-section. Depth is measured parallel to the Z-Z axis
+/// and tees have their flange at the top.
+/// </summary>
+type Section =
+  | Rectangle of width: float<mm> * depth: float<mm>
+  | Circle of diameter: float<mm>
+  | IBeam of
+    depth: float<mm> *
+    width: float<mm> *
+    webThickness: float<mm> *
+    flangeThickness: float<mm>
+  | Tee of
+    depth: float<mm> *
+    width: float<mm> *
+    webThickness: float<mm> *
+    flangeThickness: float<mm>
+  | Box of depth: float<mm> * width: float<mm> * thickness: float<mm>
+  | Pipe of diameter: float<mm> * thickness: float<mm>
+
+type SectionGeometryError = InvalidDimensions of string
+
+[<RequireQualifiedAccess>]
+module Section =
+
+  let private π = System.Math.PI
+
+  let private cube (x: float<mm>) = x * x * x
+
+  /// <summary>
+  /// Checks that all dimensions are positive and that walls, webs and
+  /// flanges fit within the overall dimensions.
+  /// </summary>
+  /// <param name="s">Parametric section.</param>
+  /// <returns>Validated section, or an error.</returns>
+  let validate (s: Section) : Result<Section, SectionGeometryError> =
+    let dimensions, fits =
+      match s with
+      | Rectangle(b, h) -> [ b; h ], true
+      | Circle d -> [ d ], true
+      | IBeam(h, b, tw, tf) -> [ h; b; tw; tf ], tw < b && 2.0 * tf < h
+      | Tee(h, b, tw, tf) -> [ h; b; tw; tf ], tw < b && tf < h
+      | Box(h, b, t) -> [ h; b; t ], 2.0 * t < b && 2.0 * t < h
+      | Pipe(d, t) -> [ d; t ], 2.0 * t < d
+
+    match dimensions |> List.forall (fun x -> x > 0.0<mm>), fits with
+    | false, _ -> Error(InvalidDimensions "Dimensions must be > 0")
+    | _, false -> Error(InvalidDimensions "Walls do not fit within section")
+    | _ -> Ok s
+
+  /// <summary>
+  /// Describes a section by its shape and dimensions, e.g.
+  /// "Rectangle 300x600".
+  /// </summary>
+  /// <param name="s">Parametric section.</param>
+  /// <returns>Section designation.</returns>
+  let designation (s: Section) : string =
+    let describe name (dimensions: float<mm> list) =
+      dimensions
+      |> List.map (RemoveUnits.fromFloat >> string)
+      |> String.concat "x"
+      |> sprintf "%s %s" name
+
+    match s with
+    | Rectangle(b, h) -> describe "Rectangle" [ b; h ]
+    | Circle d -> describe "Circle" [ d ]
+    | IBeam(h, b, tw, tf) -> describe "I" [ h; b; tw; tf ]
+    | Tee(h, b, tw, tf) -> describe "Tee" [ h; b; tw; tf ]
+    | Box(h, b, t) -> describe "Box" [ h; b; t ]
+    | Pipe(d, t) -> describe "Pipe" [ d; t ]
+
+  /// <summary>
+  /// Torsion constant of a solid rectangle (Roark).
+  /// </summary>
+  let private rectangleTorsion (b: float<mm>) (h: float<mm>) : float<mm^4> =
+    let a, c = max b h, min b h
+    let r = c / a
+    a * cube c * (1.0 / 3.0 - 0.21 * r * (1.0 - r ** 4.0 / 12.0))
+
+  let private rectangle (b: float<mm>) (h: float<mm>) =
+    let iy = b * cube h / 12.0
+    let iz = h * cube b / 12.0
+
+    { Designation = designation (Rectangle(b, h))
+      Area = b * h
+      SecondMomentY = iy
+      SecondMomentZ = iz
+      TorsionConstant = rectangleTorsion b h
+      ElasticModulusY = iy / (h / 2.0)
+      ElasticModulusZ = iz / (b / 2.0)
+      PlasticModulusY = b * h * h / 4.0
+      PlasticModulusZ = h * b * b / 4.0 }
+
+  let private circle (d: float<mm>) =
+    let i = π * d * cube d / 64.0
+
+    { Designation = designation (Circle d)
+      Area = π * d * d / 4.0
+      SecondMomentY = i
+      SecondMomentZ = i
+      TorsionConstant = 2.0 * i
+      ElasticModulusY = i / (d / 2.0)
+      ElasticModulusZ = i / (d / 2.0)
+      PlasticModulusY = cube d / 6.0
+      PlasticModulusZ = cube d / 6.0 }
+
+  let private iBeam (h: float<mm>) (b: float<mm>) tw (tf: float<mm>) =
+    let hw = h - 2.0 * tf
+    let iy = (b * cube h - (b - tw) * cube hw) / 12.0
+    let iz = (2.0 * tf * cube b + hw * cube tw) / 12.0
+
+    { Designation = designation (IBeam(h, b, tw, tf))
+      Area = 2.0 * b * tf + hw * tw
+      SecondMomentY = iy
+      SecondMomentZ = iz
+      TorsionConstant = (2.0 * b * cube tf + hw * cube tw) / 3.0
+      ElasticModulusY = iy / (h / 2.0)
+      ElasticModulusZ = iz / (b / 2.0)
+      PlasticModulusY = b * tf * (h - tf) + tw * hw * hw / 4.0
+      PlasticModulusZ = tf * b * b / 2.0 + hw * tw * tw / 4.0 }
+
+  let private tee (h: float<mm>) (b: float<mm>) tw (tf: float<mm>) =
+    let hw = h - tf
+    let flange = b * tf
+    let web = hw * tw
+    let area = flange + web
+    let centroid = (flange * tf / 2.0 + web * (tf + hw / 2.0)) / area
+    let flangeOffset = centroid - tf / 2.0
+    let webOffset = tf + hw / 2.0 - centroid
+
+    let iy =
+      b * cube tf / 12.0
+      + flange * flangeOffset * flangeOffset
+      + tw * cube hw / 12.0
+      + web * webOffset * webOffset
+
+    let iz = (tf * cube b + hw * cube tw) / 12.0
+
+    let plasticModulusY =
+      match flange >= area / 2.0 with
+      | true ->
+        let y = area / (2.0 * b)
+        b * y * y / 2.0
+        + b * (tf - y) * (tf - y) / 2.0
+        + web * (tf + hw / 2.0 - y)
+      | false ->
+        let y = tf + (area / 2.0 - flange) / tw
+        flange * (y - tf / 2.0) + tw * (y - tf) * (y - tf) / 2.0
+        + tw * (h - y) * (h - y) / 2.0
+
+    { Designation = designation (Tee(h, b, tw, tf))
+      Area = area
+      SecondMomentY = iy
+      SecondMomentZ = iz
+      TorsionConstant = (b * cube tf + hw * cube tw) / 3.0
+      ElasticModulusY = iy / max centroid (h - centroid)
+      ElasticModulusZ = iz / (b / 2.0)
+      PlasticModulusY = plasticModulusY
+      PlasticModulusZ = tf * b * b / 4.0 + hw * tw * tw / 4.0 }
+
+  let private box (h: float<mm>) (b: float<mm>) (t: float<mm>) =
+    let hi = h - 2.0 * t
+    let bi = b - 2.0 * t
+    let iy = (b * cube h - bi * cube hi) / 12.0
+    let iz = (h * cube b - hi * cube bi) / 12.0
+    let enclosed = (b - t) * (h - t)
+
+    { Designation = designation (Box(h, b, t))
+      Area = b * h - bi * hi
+      SecondMomentY = iy
+      SecondMomentZ = iz
+      TorsionConstant = 2.0 * t * enclosed * enclosed / (b + h - 2.0 * t)
+      ElasticModulusY = iy / (h / 2.0)
+      ElasticModulusZ = iz / (b / 2.0)
+      PlasticModulusY = (b * h * h - bi * hi * hi) / 4.0
+      PlasticModulusZ = (h * b * b - hi * bi * bi) / 4.0 }
+
+  let private pipe (d: float<mm>) (t: float<mm>) =
+    let di = d - 2.0 * t
+    let i = π * (d * cube d - di * cube di) / 64.0
+
+    { Designation = designation (Pipe(d, t))
+      Area = π * (d * d - di * di) / 4.0
+      SecondMomentY = i
+      SecondMomentZ = i
+      TorsionConstant = 2.0 * i
+      ElasticModulusY = i / (d / 2.0)
+      ElasticModulusZ = i / (d / 2.0)
+      PlasticModulusY = (cube d - cube di) / 6.0
+      PlasticModulusZ = (cube d - cube di) / 6.0 }
+
+  /// <summary>
+  /// Calculates the geometric properties of a parametric section.
+  /// Thin-walled approximations are used for the torsion constants of
+  /// open I and tee sections and root radii are ignored.
+  /// </summary>
+  /// <param name="s">Parametric section.</param>
+  /// <returns>Section properties, or an error.</returns>
+  let properties
+    (s: Section)
+    : Result<SectionProperties, SectionGeometryError> =
+    s
+    |> validate
+    |> Result.map (fun s ->
+      match s with
+      | Rectangle(b, h) -> rectangle b h
+      | Circle d -> circle d
+      | IBeam(h, b, tw, tf) -> iBeam h b tw tf
+      | Tee(h, b, tw, tf) -> tee h b tw tf
+      | Box(h, b, t) -> box h b t
+      | Pipe(d, t) -> pipe d t)
+
+[<RequireQualifiedAccess>]
+module SectionGeometryError =
+
+  let getAsString (e: SectionGeometryError) : string =
+    match e with
+    | InvalidDimensions msg -> $"Invalid Dimensions: {msg}."
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Concrete
+
+open Gazelle.Units
+
+/// <summary>
+/// Characteristic cylinder strength, either an ACI 318 specified
+/// strength f'c or a custom value used with the EN 1992-1-1 expressions.
+/// </summary>
+type CylinderStrength =
+  | Aci of float<psi>
+  | Custom of float<MPa>
+
+/// <summary>
+/// Concrete properties derived from the cylinder strength. ACI strengths
+/// use the ACI 318-19 correlations and custom strengths use the
+/// EN 1992-1-1 Table 3.1 expressions.
+/// </summary>
+[<RequireQualifiedAccess>]
+module CylinderStrength =
+
+  let private sqrtMPa (fc: float<MPa>) : float<MPa> =
+    sqrt (RemoveUnits.fromFloat fc) * 1.0<MPa>
+
+  /// <summary>
+  /// Characteristic cylinder strength, fck or f'c.
+  /// </summary>
+  let fck (s: CylinderStrength) : float<MPa> =
+    match s with
+    | Aci fc -> Convert.psiToMegapascals fc
+    | Custom fck -> fck
+
+  /// <summary>
+  /// Mean cylinder strength. For ACI strengths this is the required
+  /// average strength f'cr without test records (Table 26.12.3.1(b)).
+  /// </summary>
+  let fcm (s: CylinderStrength) : float<MPa> =
+    match s, fck s with
+    | Aci _, fc when fc < 21.0<MPa> -> fc + 7.0<MPa>
+    | Aci _, fc when fc <= 35.0<MPa> -> fc + 8.3<MPa>
+    | Aci _, fc -> 1.1 * fc + 5.0<MPa>
+    | Custom _, fck -> fck + 8.0<MPa>
+
+  /// <summary>
+  /// Mean tensile strength. For ACI strengths this is the splitting
+  /// tensile strength of normal weight concrete, 0.56√f'c.
+  /// </summary>
+  let fctm (s: CylinderStrength) : float<MPa> =
+    match s with
+    | Aci _ -> 0.56 * sqrtMPa (fck s)
+    | Custom fck -> EC2.fctm fck
+
+  /// <summary>
+  /// Modulus of elasticity. For ACI strengths this is 4700√f'c for
+  /// normal weight concrete (19.2.2.1(b)).
+  /// </summary>
+  let ecm (s: CylinderStrength) : float<MPa> =
+    match s with
+    | Aci _ -> 4700.0 * sqrtMPa (fck s)
+    | Custom fck -> EC2.ecm fck
+
+  /// <summary>
+  /// Ultimate compressive strain. ACI strengths use 0.003 (22.2.2.1)
+  /// and custom strengths use εcu2 from EN 1992-1-1 Table 3.1.
+  /// </summary>
+  let ultimateStrain (s: CylinderStrength) : float =
+    match s, RemoveUnits.fromFloat (fck s) with
+    | Aci _, _ -> 0.003
+    | Custom _, fck when fck <= 50.0 -> 0.0035
+    | Custom _, fck -> (2.6 + 35.0 * ((90.0 - fck) / 100.0) ** 4.0) / 1000.0
@@ -0,0 +1,447 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Concrete
+
+open Gazelle.Units
+open Gazelle.Design
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Bending reinforcement required at the ultimate limit state.
+/// </summary>
+type FlexuralDesign =
+  { LeverArm: float<mm>
+    TensionSteel: float<mm^2>
+    CompressionSteel: float<mm^2> }
+
+/// <summary>
+/// Shear reinforcement required at the ultimate limit state.
+/// </summary>
+type ShearDesign =
+  { ConcreteResistance: float<kN>
+    CotTheta: float
+    Links: float<mm^2 / mm> }
+
+/// <summary>
+/// Reinforced concrete beam design to EN 1992-1-1 using the rectangular
+/// stress block. Partial factors follow the UK National Annex and
+/// moment redistribution is not considered.
+/// </summary>
+[<RequireQualifiedAccess>]
+module EC2 =
+
+  let private γc = 1.5
+  let private γs = 1.15
+  let private es = 200_000.0<MPa>
+  let private kLimit = 0.167
+  let private crackWidthLimit = 0.3<mm>
+
+  let private toNewtonMillimetres (x: float<kNm>) =
+    Convert.kilonewtonMetresToNewtonMillimetres x
+
+  /// <summary>
+  /// Mean axial tensile strength, fctm (Table 3.1).
+  /// </summary>
+  /// <param name="fck">Characteristic cylinder strength.</param>
+  /// <returns>Mean tensile strength.</returns>
+  let fctm (fck: float<MPa>) : float<MPa> =
+    let fck = RemoveUnits.fromFloat fck
+
+    match fck with
+    | fck when fck <= 50.0 -> 0.30 * fck ** (2.0 / 3.0) * 1.0<MPa>
+    | fck -> 2.12 * log (1.0 + (fck + 8.0) / 10.0) * 1.0<MPa>
+
+  /// <summary>
+  /// Secant modulus of elasticity, Ecm (Table 3.1).
+  /// </summary>
+  /// <param name="fck">Characteristic cylinder strength.</param>
+  /// <returns>Modulus of elasticity.</returns>
+  let ecm (fck: float<MPa>) : float<MPa> =
+    22_000.0<MPa> * ((fck + 8.0<MPa>) / 10.0<MPa>) ** 0.3
+
+  [<RequireQualifiedAccess>]
+  module Flexure =
+
+    /// <summary>
+    /// Reinforcement for a rectangular section in bending, adding
+    /// compression steel where K exceeds K' = 0.167.
+    /// </summary>
+    /// <param name="b">Width of the compression zone.</param>
+    /// <param name="d">Effective depth.</param>
+    /// <param name="d2">Depth to the compression steel.</param>
+    /// <param name="fck">Characteristic cylinder strength.</param>
+    /// <param name="fyk">Characteristic yield strength of rebar.</param>
+    /// <param name="moment">Design moment.</param>
+    /// <returns>Required reinforcement.</returns>
+    let rectangular
+      (b: float<mm>)
+      (d: float<mm>)
+      (d2: float<mm>)
+      (fck: float<MPa>)
+      (fyk: float<MPa>)
+      (moment: float<kNm>)
+      : FlexuralDesign =
+      let m = toNewtonMillimetres (abs moment)
+      let fyd = fyk / γs
+      let k = m / (b * d * d * fck)
+      let z = min (0.95 * d) (d * (0.5 + sqrt (0.25 - min k kLimit / 1.134)))
+
+      match k with
+      | k when k <= kLimit ->
+        { LeverArm = z
+          TensionSteel = m / (fyd * z)
+          CompressionSteel = 0.0<mm^2> }
+      | k ->
+        let as2 = (k - kLimit) * fck * b * d * d / (fyd * (d - d2))
+
+        { LeverArm = z
+          TensionSteel = kLimit * fck * b * d * d / (fyd * z) + as2
+          CompressionSteel = as2 }
+
+    /// <summary>
+    /// Reinforcement required to resist the design moment. Flanged
+    /// sections in hogging are designed as rectangular sections using
+    /// the web width.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="moment">Design moment, sagging positive.</param>
+    /// <returns>Required reinforcement.</returns>
+    let design (beam: RCBeam) (moment: float<kNm>) : FlexuralDesign =
+      let d = RCBeam.effectiveDepth beam
+      let d2 = RCBeam.compressionSteelDepth beam
+      let fck = beam.Fck
+      let fyk = beam.Fyk
+
+      match beam.Section with
+      | Rectangular(b, _) -> rectangular b d d2 fck fyk moment
+      | Flanged(bw, _, _, _) when moment < 0.0<kNm> ->
+        rectangular bw d d2 fck fyk moment
+      | Flanged(bw, bf, hf, _) ->
+        let m = toNewtonMillimetres moment
+        let flangeResistance = 0.567 * fck * bf * hf * (d - 0.5 * hf)
+
+        match m with
+        | m when m <= flangeResistance -> rectangular bf d d2 fck fyk moment
+        | m ->
+          let z = d - 0.5 * hf
+          let web = 0.1 * fck * bw * d * (0.36 * d - hf)
+
+          { LeverArm = z
+            TensionSteel = (m + web) / (fyk / γs * z)
+            CompressionSteel = 0.0<mm^2> }
+
+    /// <summary>
+    /// Minimum area of longitudinal tension reinforcement (9.1N).
+    /// </summary>
+    let minimumSteel (beam: RCBeam) : float<mm^2> =
+      let btd = RCBeam.webWidth beam * RCBeam.effectiveDepth beam
+      max (0.26 * fctm beam.Fck / beam.Fyk * btd) (0.0013 * btd)
+
+    /// <summary>
+    /// Maximum area of tension or compression reinforcement, 0.04 Ac.
+    /// </summary>
+    let maximumSteel (beam: RCBeam) : float<mm^2> = 0.04 * RCBeam.area beam
+
+  [<RequireQualifiedAccess>]
+  module Shear =
+
+    let private leverArm (beam: RCBeam) = 0.9 * RCBeam.effectiveDepth beam
+
+    let private strutStrength (beam: RCBeam) : float<MPa> =
+      let ν1 = 0.6 * (1.0 - beam.Fck / 250.0<MPa>)
+      ν1 * beam.Fck / γc
+
+    /// <summary>
+    /// Design shear resistance of a member without shear reinforcement,
+    /// VRd,c (6.2).
+    /// </summary>
+    let concreteResistance (beam: RCBeam) : float<kN> =
+      let bw = RCBeam.webWidth beam
+      let d = RCBeam.effectiveDepth beam
+      let fck = RemoveUnits.fromFloat beam.Fck
+      let k = min 2.0 (1.0 + sqrt (200.0<mm> / d))
+      let ρl = min 0.02 (beam.Reinforcement.Tension / (bw * d))
+      let vRdc = 0.18 / γc * k * (100.0 * ρl * fck) ** (1.0 / 3.0)
+      let vMin = 0.035 * k ** 1.5 * sqrt fck
+      max vRdc vMin * 1.0<MPa> * bw * d |> Convert.newtonsToKilonewtons
+
+    /// <summary>
+    /// Maximum shear force limited by crushing of the compression struts,
+    /// VRd,max (6.9), taking αcw = 1.0.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="cotθ">Cotangent of the strut angle.</param>
+    /// <returns>Maximum shear resistance.</returns>
+    let maximumResistance (beam: RCBeam) (cotθ: float) : float<kN> =
+      let bw = RCBeam.webWidth beam
+
+      bw * leverArm beam * strutStrength beam / (cotθ + 1.0 / cotθ)
+      |> Convert.newtonsToKilonewtons
+
+    /// <summary>
+    /// Strut angle for the design shear force, preferring cot θ = 2.5 and
+    /// reducing towards cot θ = 1.0 to avoid strut crushing.
+    /// </summary>
+    let strutAngle (beam: RCBeam) (shear: float<kN>) : float =
+      let v = Convert.kilonewtonsToNewtons (abs shear)
+      let capacity = RCBeam.webWidth beam * leverArm beam * strutStrength beam
+
+      match abs shear with
+      | s when s <= maximumResistance beam 2.5 -> 2.5
+      | s when s >= maximumResistance beam 1.0 -> 1.0
+      | _ -> 1.0 / tan (0.5 * asin (2.0 * v / capacity))
+
+    /// <summary>
+    /// Minimum area of shear reinforcement per unit length (9.5N).
+    /// </summary>
+    let minimumLinks (beam: RCBeam) : float<mm^2 / mm> =
+      let ρw = 0.08 * sqrt (RemoveUnits.fromFloat beam.Fck) * 1.0<MPa>
+      ρw / beam.Fyk * RCBeam.webWidth beam
+
+    /// <summary>
+    /// Design shear resistance of vertical links, VRd,s (6.8).
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="cotθ">Cotangent of the strut angle.</param>
+    /// <returns>Shear resistance of the links.</returns>
+    let linkResistance (beam: RCBeam) (cotθ: float) : float<kN> =
+      let fywd = beam.Fyk / γs
+
+      beam.Reinforcement.Links * leverArm beam * fywd * cotθ
+      |> Convert.newtonsToKilonewtons
+
+    /// <summary>
+    /// Shear links required to resist the design shear force.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="shear">Design shear force.</param>
+    /// <returns>Required shear reinforcement.</returns>
+    let design (beam: RCBeam) (shear: float<kN>) : ShearDesign =
+      let cotθ = strutAngle beam shear
+      let v = Convert.kilonewtonsToNewtons (abs shear)
+      let required = v / (leverArm beam * beam.Fyk / γs * cotθ)
+
+      { ConcreteResistance = concreteResistance beam
+        CotTheta = cotθ
+        Links = max required (minimumLinks beam) }
+
+  [<RequireQualifiedAccess>]
+  module Serviceability =
+
+    /// <summary>
+    /// Widths of the compression zone and flange thickness for the
+    /// given moment. Flanges in tension are ignored.
+    /// </summary>
+    let private compressionZone (beam: RCBeam) (moment: float<kNm>) =
+      match beam.Section with
+      | Rectangular(b, _) -> b, b, 0.0<mm>
+      | Flanged(bw, _, _, _) when moment < 0.0<kNm> -> bw, bw, 0.0<mm>
+      | Flanged(bw, bf, hf, _) -> bw, bf, hf
+
+    /// <summary>
+    /// Depth of the neutral axis of the cracked, elastic section.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="moment">Service moment, sagging positive.</param>
+    /// <returns>Neutral axis depth.</returns>
+    let neutralAxis (beam: RCBeam) (moment: float<kNm>) : float<mm> =
+      let bw, bf, hf = compressionZone beam moment
+      let αe = es / ecm beam.Fck
+      let d = RCBeam.effectiveDepth beam
+      let ast = beam.Reinforcement.Tension
+      let solve (a: float<mm>) (b: float<mm^2>) (c: float<mm^3>) =
+        (-b + sqrt (b * b - 4.0 * a * c)) / (2.0 * a)
+
+      match solve (bf / 2.0) (αe * ast) (-(αe * ast * d)) with
+      | x when x <= hf || hf = 0.0<mm> -> x
+      | _ ->
+        solve
+          (bw / 2.0)
+          ((bf - bw) * hf + αe * ast)
+          (-((bf - bw) * hf * hf / 2.0 + αe * ast * d))
+
+    /// <summary>
+    /// Stress in the tension reinforcement of the cracked section.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="moment">Service moment, sagging positive.</param>
+    /// <returns>Reinforcement stress.</returns>
+    let steelStress (beam: RCBeam) (moment: float<kNm>) : float<MPa> =
+      let bw, bf, hf = compressionZone beam moment
+      let αe = es / ecm beam.Fck
+      let d = RCBeam.effectiveDepth beam
+      let ast = beam.Reinforcement.Tension
+      let x = neutralAxis beam moment
+      let flange = min x hf
+      let m = toNewtonMillimetres (abs moment)
+
+      let icr =
+        bw * x * x * x / 3.0
+        + (bf - bw)
+          * (flange * flange * flange / 12.0
+             + flange * (x - flange / 2.0) * (x - flange / 2.0))
+        + αe * ast * (d - x) * (d - x)
+
+      αe * m * (d - x) / icr
+
+    /// <summary>
+    /// Calculated crack width, wk (7.8), for long-term loading with
+    /// high bond bars.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="moment">Service moment, sagging positive.</param>
+    /// <returns>Crack width.</returns>
+    let crackWidth (beam: RCBeam) (moment: float<kNm>) : float<mm> =
+      let r = beam.Reinforcement
+      let h = RCBeam.height beam
+      let d = RCBeam.effectiveDepth beam
+      let x = neutralAxis beam moment
+      let σs = steelStress beam moment
+      let αe = es / ecm beam.Fck
+      let fct = fctm beam.Fck
+
+      let bt =
+        match beam.Section with
+        | Flanged(_, bf, _, _) when moment < 0.0<kNm> -> bf
+        | _ -> RCBeam.webWidth beam
+
+      let hcEff = List.min [ 2.5 * (h - d); (h - x) / 3.0; h / 2.0 ]
+      let ρ = r.Tension / (bt * hcEff)
+      let c = beam.Cover + r.LinkDiameter
+      let srMax = 3.4 * c + 0.425 * 0.8 * 0.5 * r.BarDiameter / ρ
+      let strain = (σs - 0.4 * fct / ρ * (1.0 + αe * ρ)) / es
+      srMax * max strain (0.6 * σs / es)
+
+    /// <summary>
+    /// Limiting span to effective depth ratio (7.16a/7.16b), modified
+    /// for steel stress, flanged sections and spans over 7 m.
+    /// </summary>
+    /// <param name="beam">Reinforced concrete beam.</param>
+    /// <param name="flexure">Reinforcement required at mid-span.</param>
+    /// <returns>Limiting span to depth ratio.</returns>
+    let spanDepthLimit (beam: RCBeam) (flexure: FlexuralDesign) : float =
+      let fck = RemoveUnits.fromFloat beam.Fck
+      let d = RCBeam.effectiveDepth beam
+      let _, b, _ = compressionZone beam 1.0<kNm>
+      let required = max flexure.TensionSteel (Flexure.minimumSteel beam)
+      let ρ0 = sqrt fck / 1000.0
+      let ρ = required / (b * d)
+      let ρ' = flexure.CompressionSteel / (b * d)
+
+      let k =
+        match beam.SpanType with
+        | SimplySupported -> 1.0
+        | EndSpan -> 1.3
+        | InteriorSpan -> 1.5
+        | Cantilever -> 0.4
+
+      let basic =
+        match ρ with
+        | ρ when ρ <= ρ0 ->
+          11.0
+          + 1.5 * sqrt fck * ρ0 / ρ
+          + 3.2 * sqrt fck * (ρ0 / ρ - 1.0) ** 1.5
+        | ρ ->
+          11.0 + 1.5 * sqrt fck * ρ0 / (ρ - ρ')
+          + sqrt fck / 12.0 * sqrt (ρ' / ρ0)
+
+      let stress =
+        min 1.5 (500.0<MPa> / beam.Fyk * beam.Reinforcement.Tension / required)
+
+      let flanged =
+        match beam.Section with
+        | Flanged(bw, bf, _, _) when bf / bw > 3.0 -> 0.8
+        | _ -> 1.0
+
+      let span =
+        match beam.SpanType, beam.Span with
+        | Cantilever, _ -> 1.0
+        | _, l when l > 7000.0<mm> -> 7000.0<mm> / l
+        | _ -> 1.0
+
+      k * basic * stress * flanged * span
+
+  let private validate (beam: RCBeam) : Result<RCBeam, DesignError> =
+    let r = beam.Reinforcement
+
+    match beam.Section with
+    | _ when RCBeam.webWidth beam <= 0.0<mm> ->
+      Error(InvalidInput "Width <= 0")
+    | Flanged(bw, bf, _, _) when bf < bw ->
+      Error(InvalidInput "Flange width < web width")
+    | Flanged(_, _, hf, h) when hf <= 0.0<mm> || hf >= h ->
+      Error(InvalidInput "Flange depth must lie between 0 and the height")
+    | _ when RCBeam.effectiveDepth beam <= 0.0<mm> ->
+      Error(InvalidInput "Effective depth <= 0")
+    | _ when beam.Fck < 12.0<MPa> || beam.Fck > 90.0<MPa> ->
+      Error(InvalidInput "fck must lie between 12 and 90 MPa")
+    | _ when beam.Fyk <= 0.0<MPa> -> Error(InvalidInput "fyk <= 0")
+    | _ when beam.Span <= 0.0<mm> -> Error(InvalidInput "Span <= 0")
+    | _ when r.Tension <= 0.0<mm^2> ->
+      Error(InvalidInput "Tension reinforcement <= 0")
+    | _ -> Ok beam
+
+  /// <summary>
+  /// Performs ultimate and serviceability limit state checks for a
+  /// reinforced concrete beam. The crack width limit is 0.3 mm.
+  /// </summary>
+  /// <param name="beam">Reinforced concrete beam.</param>
+  /// <param name="f">Design forces, sagging positive.</param>
+  /// <returns>Design report or an error.</returns>
+  let check
+    (beam: RCBeam)
+    (f: BeamForces)
+    : Result<DesignReport, DesignError> =
+    match validate beam with
+    | Error e -> Error e
+    | Ok beam ->
+      let r = beam.Reinforcement
+      let flexure = Flexure.design beam f.Moment
+      let shear = Shear.design beam f.Shear
+      let vRdmax = Shear.maximumResistance beam shear.CotTheta
+      let vRds = Shear.linkResistance beam shear.CotTheta
+      let actualSpanDepth = beam.Span / RCBeam.effectiveDepth beam
+
+      let checks =
+        [ DesignCheck.create "Bending" "6.1" flexure.TensionSteel r.Tension
+          if flexure.CompressionSteel > 0.0<mm^2> then
+            DesignCheck.create
+              "Compression Reinforcement"
+              "6.1"
+              flexure.CompressionSteel
+              r.Compression
+          DesignCheck.create
+            "Minimum Reinforcement"
+            "9.2.1.1(1)"
+            (Flexure.minimumSteel beam)
+            r.Tension
+          DesignCheck.create
+            "Maximum Reinforcement"
+            "9.2.1.1(3)"
+            (max r.Tension r.Compression)
+            (Flexure.maximumSteel beam)
+          DesignCheck.create "Shear" "6.2.3" f.Shear (min vRds vRdmax)
+          DesignCheck.create
+            "Minimum Shear Reinforcement"
+            "9.2.2(5)"
+            (Shear.minimumLinks beam)
+            r.Links
+          DesignCheck.create
+            "Crack Width"
+            "7.3.4"
+            (Serviceability.crackWidth beam f.ServiceMoment)
+            crackWidthLimit
+          DesignCheck.ofUtilisation
+            "Span to Depth Ratio"
+            "7.4.2"
+            (actualSpanDepth / Serviceability.spanDepthLimit beam flexure) ]
+
+      checks |> DesignReport.create "EN 1992-1-1" beam.Name |> Ok
+
+  /// <summary>
+  /// EN 1992-1-1 beam design code plugin.
+  /// </summary>
+  let designCode: MemberDesignCode<RCBeam, BeamForces> =
+    { Name = "EN 1992-1-1"; Check = check }
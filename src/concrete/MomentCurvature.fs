@@ -0,0 +1,313 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Concrete
+
+open Gazelle.Units
+open Gazelle.Design
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Layer of reinforcement at a depth measured from the top face.
+/// </summary>
+type RebarLayer = { Depth: float<mm>; Area: float<mm^2> }
+
+/// <summary>
+/// Reinforced concrete cross-section for fibre analysis. Sagging
+/// bending is assumed, i.e. the top face is in compression.
+/// </summary>
+type FibreSection =
+  { Section: BeamSection
+    Fck: float<MPa>
+    Fyk: float<MPa>
+    Layers: RebarLayer list }
+
+/// <summary>
+/// Point on a moment-curvature curve with the depth of the neutral axis
+/// below the top face.
+/// </summary>
+type MomentCurvaturePoint =
+  { Curvature: float<1 / mm>
+    Moment: float<kNm>
+    NeutralAxis: float<mm> }
+
+/// <summary>
+/// Moment-curvature response of a section. Yield is None where the
+/// concrete crushes before the tension steel yields. Cracked stiffness
+/// is the secant stiffness at yield, or at ultimate if the steel does
+/// not yield.
+/// </summary>
+type MomentCurvature =
+  { Points: MomentCurvaturePoint list
+    Cracking: MomentCurvaturePoint
+    Yield: MomentCurvaturePoint option
+    Ultimate: MomentCurvaturePoint
+    CrackedStiffness: float<N * mm^2> }
+
+[<RequireQualifiedAccess>]
+module FibreSection =
+
+  /// <summary>
+  /// Creates a fibre section from the sagging reinforcement of a beam.
+  /// </summary>
+  /// <param name="beam">Reinforced concrete beam.</param>
+  /// <returns>Fibre section.</returns>
+  let ofBeam (beam: RCBeam) : FibreSection =
+    let r = beam.Reinforcement
+
+    let compression =
+      match r.Compression with
+      | a when a > 0.0<mm^2> ->
+        [ { Depth = RCBeam.compressionSteelDepth beam; Area = a } ]
+      | _ -> []
+
+    { Section = beam.Section
+      Fck = beam.Fck
+      Fyk = beam.Fyk
+      Layers =
+        { Depth = RCBeam.effectiveDepth beam
+          Area = r.Tension }
+        :: compression }
+
+/// <summary>
+/// Moment-curvature analysis of reinforced concrete sections using
+/// characteristic material strengths. Concrete follows the EN 1992-1-1
+/// parabola-rectangle in compression and is linear up to fctm in
+/// tension, while reinforcement is elastic-perfectly plastic.
+/// </summary>
+[<RequireQualifiedAccess>]
+module MomentCurvature =
+
+  let private es = 200_000.0
+  let private fibres = 200
+  let private steps = 50
+  let private iterations = 100
+
+  let private height (s: FibreSection) : float =
+    match s.Section with
+    | Rectangular(_, h)
+    | Flanged(_, _, _, h) -> RemoveUnits.fromFloat h
+
+  let private width (s: FibreSection) (y: float) : float =
+    match s.Section with
+    | Rectangular(b, _) -> RemoveUnits.fromFloat b
+    | Flanged(_, bf, hf, _) when y < RemoveUnits.fromFloat hf ->
+      RemoveUnits.fromFloat bf
+    | Flanged(bw, _, _, _) -> RemoveUnits.fromFloat bw
+
+  /// <summary>
+  /// Strain at peak stress, ultimate strain and exponent of the
+  /// parabola-rectangle (Table 3.1).
+  /// </summary>
+  let private parabolaRectangle (fck: float) =
+    match fck with
+    | fck when fck <= 50.0 -> 0.002, 0.0035, 2.0
+    | fck ->
+      let r = ((90.0 - fck) / 100.0) ** 4.0
+      let εc2 = 0.002 + 0.000085 * (fck - 50.0) ** 0.53
+      εc2, 0.0026 + 0.035 * r, 1.4 + 23.4 * r
+
+  let private ultimateStrain (s: FibreSection) : float =
+    let _, εcu2, _ = parabolaRectangle (RemoveUnits.fromFloat s.Fck)
+    εcu2
+
+  let private concrete (s: FibreSection) : float -> float =
+    let fck = RemoveUnits.fromFloat s.Fck
+    let ecm = EC2.ecm s.Fck |> RemoveUnits.fromFloat
+    let εcr = RemoveUnits.fromFloat (EC2.fctm s.Fck) / ecm
+    let εc2, _, n = parabolaRectangle fck
+
+    fun ε ->
+      match ε with
+      | ε when ε >= εc2 -> fck
+      | ε when ε >= 0.0 -> fck * (1.0 - (1.0 - ε / εc2) ** n)
+      | ε when ε >= -εcr -> ecm * ε
+      | _ -> 0.0
+
+  let private steel (s: FibreSection) (ε: float) : float =
+    let fy = RemoveUnits.fromFloat s.Fyk
+    max (-fy) (min fy (es * ε))
+
+  /// <summary>
+  /// Axial force and moment about the top face for a strain profile,
+  /// with compression positive.
+  /// </summary>
+  let private resultant (s: FibreSection) (strain: float -> float) =
+    let σc = concrete s
+    let h = height s
+    let dy = h / float fibres
+
+    let concreteForces =
+      [ for i in 0 .. fibres - 1 do
+          let y = (float i + 0.5) * dy
+          σc (strain y) * width s y * dy, y ]
+
+    let steelForces =
+      [ for layer in s.Layers do
+          let d = RemoveUnits.fromFloat layer.Depth
+          let ε = strain d
+          (steel s ε - σc ε) * RemoveUnits.fromFloat layer.Area, d ]
+
+    let forces = concreteForces @ steelForces
+    forces |> List.sumBy fst, -(forces |> List.sumBy (fun (f, y) -> f * y))
+
+  /// <summary>
+  /// Finds the root of a function that increases from negative at lo to
+  /// positive at hi by bisection.
+  /// </summary>
+  let private bisect (f: float -> float) (lo: float) (hi: float) : float =
+    let rec loop lo hi i =
+      let mid = (lo + hi) / 2.0
+
+      match i with
+      | 0 -> mid
+      | _ when f mid > 0.0 -> loop lo mid (i - 1)
+      | _ -> loop mid hi (i - 1)
+
+    loop lo hi iterations
+
+  /// <summary>
+  /// Solves for the neutral axis depth, x, that gives zero axial force
+  /// where strain is a function of x and depth.
+  /// </summary>
+  let private solve
+    (s: FibreSection)
+    (profile: float -> float -> float)
+    (hi: float)
+    : MomentCurvaturePoint =
+    let h = height s
+    let force x = resultant s (profile x) |> fst
+    let x = bisect force (1e-9 * h) hi
+    let strain = profile x
+    let _, m = resultant s strain
+
+    { Curvature = (strain 0.0 - strain h) / h * 1.0<1 / mm>
+      Moment = m * 1.0<Nmm> |> Convert.newtonMillimetresToKilonewtonMetres
+      NeutralAxis = x * 1.0<mm> }
+
+  let private validate (s: FibreSection) =
+    let h = height s * 1.0<mm>
+    let inside l = l.Depth > 0.0<mm> && l.Depth < h
+    let tension l = 2.0 * l.Depth > h
+
+    let dimensions =
+      match s.Section with
+      | Rectangular(b, h) -> [ b; h ]
+      | Flanged(bw, bf, hf, h) -> [ bw; bf; hf; h ]
+
+    match s with
+    | _ when dimensions |> List.exists (fun x -> x <= 0.0<mm>) ->
+      Error(InvalidInput "Section dimensions must be > 0")
+    | s when s.Fck <= 0.0<MPa> || s.Fyk <= 0.0<MPa> ->
+      Error(InvalidInput "Material strengths must be > 0")
+    | s when s.Layers |> List.exists (fun l -> l.Area <= 0.0<mm^2>) ->
+      Error(InvalidInput "Reinforcement areas must be > 0")
+    | s when not (s.Layers |> List.forall inside) ->
+      Error(InvalidInput "Reinforcement must lie within the section")
+    | s when not (s.Layers |> List.exists tension) ->
+      Error(InvalidInput "Section has no tension reinforcement")
+    | s -> Ok s
+
+  /// <summary>
+  /// Calculates the moment-curvature response of a section in sagging
+  /// from first cracking up to crushing of the concrete.
+  /// </summary>
+  /// <param name="section">Fibre section.</param>
+  /// <returns>Moment-curvature response, or an error.</returns>
+  let analyse
+    (section: FibreSection)
+    : Result<MomentCurvature, DesignError> =
+    section
+    |> validate
+    |> Result.map (fun s ->
+      let h = height s
+      let εcu = ultimateStrain s
+      let ecm = EC2.ecm s.Fck
+      let εcr = EC2.fctm s.Fck / ecm
+      let εy = s.Fyk / (es * 1.0<MPa>)
+
+      let deepest =
+        s.Layers
+        |> List.map (fun l -> RemoveUnits.fromFloat l.Depth)
+        |> List.max
+
+      let cracking = solve s (fun x y -> εcr * (x - y) / (h - x)) h
+      let yielding = solve s (fun x y -> εy * (x - y) / (deepest - x)) deepest
+
+      let curve =
+        [ for i in 1..steps do
+            let εtop = εcu * float i / float steps
+            solve s (fun x y -> εtop * (x - y) / x) h ]
+
+      let ultimate = List.last curve
+
+      let yieldPoint =
+        let x = RemoveUnits.fromFloat yielding.NeutralAxis
+
+        match εy * x / (deepest - x) with
+        | εtop when εtop <= εcu -> Some yielding
+        | _ -> None
+
+      let secant = yieldPoint |> Option.defaultValue ultimate
+
+      let origin =
+        { cracking with
+            Curvature = 0.0<1 / mm>
+            Moment = 0.0<kNm> }
+
+      { Points =
+          origin :: cracking :: Option.toList yieldPoint @ curve
+          |> List.sortBy (fun p -> p.Curvature)
+        Cracking = cracking
+        Yield = yieldPoint
+        Ultimate = ultimate
+        CrackedStiffness =
+          Convert.kilonewtonMetresToNewtonMillimetres secant.Moment
+          / secant.Curvature })
+
+  /// <summary>
+  /// Ultimate moment using the rectangular stress block (3.1.7) with
+  /// characteristic strengths, for use where the full curve is not
+  /// needed or as a check on the fibre analysis.
+  /// </summary>
+  /// <param name="section">Fibre section.</param>
+  /// <returns>Ultimate moment, or an error.</returns>
+  let stressBlockMoment
+    (section: FibreSection)
+    : Result<float<kNm>, DesignError> =
+    section
+    |> validate
+    |> Result.map (fun s ->
+      let fck = RemoveUnits.fromFloat s.Fck
+      let λ = min 0.8 (0.8 - (fck - 50.0) / 400.0)
+      let η = min 1.0 (1.0 - (fck - 50.0) / 200.0)
+      let εcu = ultimateStrain s
+
+      let compression (a: float) =
+        match s.Section with
+        | Flanged(bw, bf, hf, _) when a > RemoveUnits.fromFloat hf ->
+          let bw, bf, hf = float bw, float bf, float hf
+          let flange = bf * hf
+          let web = bw * (a - hf)
+          flange + web, flange * hf / 2.0 + web * (hf + a) / 2.0
+        | _ ->
+          let area = width s 0.0 * a
+          area, area * a / 2.0
+
+      let resultant x =
+        let area, moment = compression (λ * x)
+
+        let steelForces =
+          [ for layer in s.Layers do
+              let d = RemoveUnits.fromFloat layer.Depth
+              let ε = εcu * (x - d) / x
+              let σc = if d < λ * x then η * fck else 0.0
+              (steel s ε - σc) * RemoveUnits.fromFloat layer.Area, d ]
+
+        η * fck * area + List.sumBy fst steelForces,
+        -(η * fck * moment + List.sumBy (fun (f, d) -> f * d) steelForces)
+
+      let x = bisect (resultant >> fst) (1e-9 * height s) (height s)
+      let _, m = resultant x
+      m * 1.0<Nmm> |> Convert.newtonMillimetresToKilonewtonMetres)
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Concrete
+
+open Gazelle.Units
+
+/// <summary>
+/// Beam cross-section. Flanged sections are T sections with the flange
+/// at the top of the beam.
+/// </summary>
+type BeamSection =
+  | Rectangular of width: float<mm> * height: float<mm>
+  | Flanged of
+    webWidth: float<mm> *
+    flangeWidth: float<mm> *
+    flangeDepth: float<mm> *
+    height: float<mm>
+
+/// <summary>
+/// Support conditions used to select the basic span to depth ratio.
+/// </summary>
+type SpanType =
+  | SimplySupported
+  | EndSpan
+  | InteriorSpan
+  | Cantilever
+
+/// <summary>
+/// Reinforcement provided in a beam. Tension and compression steel are
+/// measured relative to the design moment, i.e. for hogging moments the
+/// tension steel is in the top of the section.
+/// </summary>
+type BeamReinforcement =
+  { Tension: float<mm^2>
+    Compression: float<mm^2>
+    BarDiameter: float<mm>
+    LinkDiameter: float<mm>
+    Links: float<mm^2 / mm> }
+
+/// <summary>
+/// Reinforced concrete beam. Cover is the nominal cover to the links.
+/// </summary>
+type RCBeam =
+  { Name: string
+    Section: BeamSection
+    Fck: float<MPa>
+    Fyk: float<MPa>
+    Cover: float<mm>
+    Span: float<mm>
+    SpanType: SpanType
+    Reinforcement: BeamReinforcement }
+
+/// <summary>
+/// Design forces acting on a beam. Sagging moments are positive.
+/// </summary>
+type BeamForces =
+  { Moment: float<kNm>
+    Shear: float<kN>
+    ServiceMoment: float<kNm> }
+
+[<RequireQualifiedAccess>]
+module RCBeam =
+
+  /// <summary>
+  /// Overall depth of the beam.
+  /// </summary>
+  let height (beam: RCBeam) : float<mm> =
+    match beam.Section with
+    | Rectangular(_, h)
+    | Flanged(_, _, _, h) -> h
+
+  /// <summary>
+  /// Width of the web, or the full width of a rectangular section.
+  /// </summary>
+  let webWidth (beam: RCBeam) : float<mm> =
+    match beam.Section with
+    | Rectangular(b, _)
+    | Flanged(b, _, _, _) -> b
+
+  /// <summary>
+  /// Gross area of concrete.
+  /// </summary>
+  let area (beam: RCBeam) : float<mm^2> =
+    match beam.Section with
+    | Rectangular(b, h) -> b * h
+    | Flanged(bw, bf, hf, h) -> bw * h + (bf - bw) * hf
+
+  /// <summary>
+  /// Depth from the compression face to the centroid of the tension steel.
+  /// </summary>
+  let effectiveDepth (beam: RCBeam) : float<mm> =
+    let r = beam.Reinforcement
+    height beam - beam.Cover - r.LinkDiameter - r.BarDiameter / 2.0
+
+  /// <summary>
+  /// Depth from the compression face to the centroid of the compression
+  /// steel, assuming bars of the same diameter as the tension steel.
+  /// </summary>
+  let compressionSteelDepth (beam: RCBeam) : float<mm> =
+    let r = beam.Reinforcement
+    beam.Cover + r.LinkDiameter + r.BarDiameter / 2.0
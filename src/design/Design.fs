@@ -0,0 +1,132 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Design
+
+/// <summary>
+/// Outcome of an individual design check.
+/// </summary>
+type CheckStatus =
+  | Pass
+  | Fail
+
+/// <summary>
+/// Single design check, e.g. bending resistance, with its governing
+/// clause reference and utilisation (demand / capacity).
+/// </summary>
+type DesignCheck =
+  { Name: string
+    Clause: string
+    Utilisation: float
+    Status: CheckStatus }
+
+/// <summary>
+/// Structured collection of design checks for a member.
+/// </summary>
+type DesignReport =
+  { DesignCode: string
+    Member: string
+    Checks: DesignCheck list
+    Status: CheckStatus }
+
+/// <summary>
+/// Errors raised when a member falls outside the scope of a design code.
+/// </summary>
+type DesignError =
+  | UnsupportedSection of string
+  | InvalidInput of string
+
+/// <summary>
+/// Pluggable member design code. Each code supplies its own member and
+/// design force types, allowing new codes to be added without changes
+/// to existing ones.
+/// </summary>
+type MemberDesignCode<'TMember, 'TForces> =
+  { Name: string
+    Check: 'TMember -> 'TForces -> Result<DesignReport, DesignError> }
+
+[<RequireQualifiedAccess>]
+module DesignCheck =
+
+  /// <summary>
+  /// Creates a design check from a demand and capacity.
+  /// </summary>
+  /// <param name="name">Description of the check.</param>
+  /// <param name="clause">Design code clause reference.</param>
+  /// <param name="demand">Design action effect.</param>
+  /// <param name="capacity">Design resistance.</param>
+  /// <returns>Design check with utilisation and status.</returns>
+  let create
+    (name: string)
+    (clause: string)
+    (demand: float<'T>)
+    (capacity: float<'T>)
+    : DesignCheck =
+    let utilisation = abs (demand / capacity)
+
+    { Name = name
+      Clause = clause
+      Utilisation = utilisation
+      Status = if utilisation <= 1.0 then Pass else Fail }
+
+  /// <summary>
+  /// Creates a design check from a pre-computed utilisation.
+  /// </summary>
+  /// <param name="name">Description of the check.</param>
+  /// <param name="clause">Design code clause reference.</param>
+  /// <param name="utilisation">Ratio of demand to capacity.</param>
+  /// <returns>Design check with status.</returns>
+  let ofUtilisation
+    (name: string)
+    (clause: string)
+    (utilisation: float)
+    : DesignCheck =
+    { Name = name
+      Clause = clause
+      Utilisation = utilisation
+      Status = if utilisation <= 1.0 then Pass else Fail }
+
+[<RequireQualifiedAccess>]
+module DesignReport =
+
+  /// <summary>
+  /// Collates design checks into a report that passes only if every
+  /// check passes.
+  /// </summary>
+  /// <param name="designCode">Name of the design code.</param>
+  /// <param name="memberName">Member identifier.</param>
+  /// <param name="checks">Design checks performed.</param>
+  /// <returns>Design report.</returns>
+  let create
+    (designCode: string)
+    (memberName: string)
+    (checks: DesignCheck list)
+    : DesignReport =
+    let status =
+      match checks |> List.forall (fun c -> c.Status = Pass) with
+      | true -> Pass
+      | false -> Fail
+
+    { DesignCode = designCode
+      Member = memberName
+      Checks = checks
+      Status = status }
+
+  /// <summary>
+  /// Returns the check with the highest utilisation, if any.
+  /// </summary>
+  /// <param name="report">Design report.</param>
+  /// <returns>Governing design check.</returns>
+  let governing (report: DesignReport) : DesignCheck option =
+    match report.Checks with
+    | [] -> None
+    | checks -> checks |> List.maxBy (fun c -> c.Utilisation) |> Some
+
+[<RequireQualifiedAccess>]
+module DesignError =
+
+  let getAsString (e: DesignError) : string =
+    match e with
+    | UnsupportedSection msg -> $"Unsupported Section: {msg}."
+    | InvalidInput msg -> $"Invalid Input: {msg}."
@@ -0,0 +1,98 @@
+namespace Gazelle.IO
+
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+// ========================================
+// BUILDING MASS
+// ========================================
+
+/// Mass of the slabs at a storey level and its centre in plan.
+type StoreyMass =
+  { Name: Name
+    Elevation: Elevation
+    Mass: float<kg>
+    CentreOfMass: Point2D }
+
+[<RequireQualifiedAccess>]
+module Building =
+
+  let private cubicMetres = 1e-9<m^3 / mm^3>
+
+  let private origin: Point2D = { X = 0.0<mm>; Y = 0.0<mm> }
+
+  /// Plan area and centroid of a slab outline (shoelace formula).
+  let private outline (s: Slab) : float<mm^2> * Point2D =
+    let v = Array.ofList s.Vertices
+    let edges = [ for i in 0 .. v.Length - 1 -> v[i], v[(i + 1) % v.Length] ]
+    let cross (p: Point3D) (q: Point3D) = p.X * q.Y - q.X * p.Y
+    let area = 0.5 * List.sumBy (fun (p, q) -> cross p q) edges
+    let cx = edges |> List.sumBy (fun (p, q) -> (p.X + q.X) * cross p q)
+    let cy = edges |> List.sumBy (fun (p, q) -> (p.Y + q.Y) * cross p q)
+
+    match area with
+    | a when a = 0.0<mm^2> -> 0.0<mm^2>, origin
+    | a -> abs a, ({ X = cx / (6.0 * a); Y = cy / (6.0 * a) }: Point2D)
+
+  /// Mass-weighted centre of a set of masses.
+  let private centre (masses: (float<kg> * Point2D) list) : Point2D =
+    match List.sumBy fst masses with
+    | total when total = 0.0<kg> -> origin
+    | total ->
+      let moment (f: Point2D -> float<mm>) =
+        masses |> List.sumBy (fun (m, p) -> m * f p)
+
+      ({ X = moment (fun p -> p.X) / total
+         Y = moment (fun p -> p.Y) / total }: Point2D)
+
+  let private level (s: Storey) : Name * Elevation =
+    match s with
+    | MasterStorey s -> s.Name, s.Elevation
+    | SimilarStorey s -> s.Name, s.Elevation
+
+  /// Self-weight mass of a slab from its outline, thickness and density.
+  let slabMass (density: float<kg / m^3>) (s: Slab) : float<kg> =
+    let area, _ = outline s
+    area * Unwrap.positiveFloat s.Thickness * cubicMetres * density
+
+  /// Total slab mass of a building.
+  let totalMass (density: float<kg / m^3>) (b: Building) : float<kg> =
+    b.Slabs |> List.sumBy (slabMass density)
+
+  /// Centre of the slab mass of a building, taking each slab at its
+  /// elevation.
+  let centreOfMass (density: float<kg / m^3>) (b: Building) : Point3D =
+    let masses =
+      b.Slabs
+      |> List.map (fun s -> slabMass density s, s)
+      |> List.filter (fun (m, _) -> m > 0.0<kg>)
+
+    let plan = masses |> List.map (fun (m, s) -> m, snd (outline s)) |> centre
+    let total = List.sumBy fst masses
+
+    let z =
+      match total with
+      | total when total = 0.0<kg> -> 0.0<mm>
+      | total ->
+        let moment (m, s: Slab) = m * Unwrap.elevation s.Elevation
+        List.sumBy moment masses / total
+
+    { X = plan.X; Y = plan.Y; Z = z }
+
+  /// Slab mass and centre of mass at each storey, matching slabs to
+  /// storeys by elevation.
+  let storeyMasses
+    (density: float<kg / m^3>)
+    (b: Building)
+    : StoreyMass list =
+    [ for storey in b.Storeys do
+        let name, elevation = level storey
+
+        let masses =
+          b.Slabs
+          |> List.filter (fun s -> s.Elevation = elevation)
+          |> List.map (fun s -> slabMass density s, snd (outline s))
+
+        { Name = name
+          Elevation = elevation
+          Mass = List.sumBy fst masses
+          CentreOfMass = centre masses } ]
@@ -0,0 +1,182 @@
+namespace Gazelle.IO
+
+open System
+open System.Globalization
+open System.IO
+open System.Text.RegularExpressions
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+// ========================================
+// GROUND MOTION RECORDS
+// ========================================
+
+/// Acceleration history at a constant time step, starting at t = 0.
+type GroundMotion =
+  { Name: string
+    TimeStep: float<s>
+    Accelerations: float<m / s^2> list }
+
+[<RequireQualifiedAccess>]
+module GroundMotion =
+
+  let private g = 9.80665<m / s^2>
+
+  let private tryParseFloat (s: string) : float option =
+    let style = NumberStyles.Float
+    let culture = CultureInfo.InvariantCulture
+
+    match Double.TryParse(s.Trim(), style, culture) with
+    | true, x -> Some x
+    | false, _ -> None
+
+  let private lines (text: string) : string list =
+    text.Split([| '\n'; '\r' |], StringSplitOptions.RemoveEmptyEntries)
+    |> List.ofArray
+
+  let private tokens (line: string) : string list =
+    line.Split([| ' '; '\t'; ',' |], StringSplitOptions.RemoveEmptyEntries)
+    |> List.ofArray
+
+  /// Matches the NGA-West2 ("NPTS= 7998, DT= .0050 SEC") and the older
+  /// ("7998 0.0050 NPTS, DT") header styles.
+  let private headers =
+    [ @"NPTS\s*=\s*(\d+)\s*,\s*DT\s*=\s*([\d.Ee+-]+)"
+      @"^\s*(\d+)\s+([\d.Ee+-]+)\s+NPTS" ]
+    |> List.map (fun p -> Regex(p, RegexOptions.IgnoreCase))
+
+  let private tryParseHeader (line: string) : (int * float) option =
+    headers
+    |> List.tryPick (fun r ->
+      let m = r.Match(line)
+
+      match m.Success, tryParseFloat (m.Groups[2].Value) with
+      | true, Some dt -> Some(int (m.Groups[1].Value), dt)
+      | _ -> None)
+
+  let private create name (dt: float) (values: float list) : GroundMotion =
+    { Name = name
+      TimeStep = dt * 1.0<s>
+      Accelerations = values |> List.map (fun a -> a * g) }
+
+  /// Duration of a record.
+  let duration (gm: GroundMotion) : float<s> =
+    gm.TimeStep * float (List.length gm.Accelerations - 1)
+
+  /// Parses a PEER NGA .AT2 record, whose accelerations are in units of g.
+  let parseAT2 (name: string) (text: string) : Result<GroundMotion, IOError> =
+    let lines = lines text
+    let header = lines |> List.truncate 4 |> List.tryPick tryParseHeader
+
+    let values =
+      lines
+      |> List.skip (min 4 lines.Length)
+      |> List.collect tokens
+      |> List.map tryParseFloat
+
+    match header, values with
+    | None, _ -> Error(DeserializationError "AT2 header has no NPTS or DT")
+    | Some(_, dt), _ when dt <= 0.0 ->
+      Error(DeserializationError "AT2 time step must be > 0")
+    | _, values when values |> List.exists Option.isNone ->
+      Error(DeserializationError "AT2 record contains non-numeric values")
+    | Some(npts, _), values when values.Length <> npts ->
+      let msg = $"Expected {npts} points, found {values.Length}"
+      Error(DeserializationError msg)
+    | Some(_, dt), values -> Ok(create name dt (List.choose id values))
+
+  /// Splits a CSV row into trimmed fields, dropping empty trailing fields
+  /// left by spreadsheet exports, e.g. "0.02,0.1,,".
+  let private fields (line: string) : string[] =
+    line.Split(',')
+    |> Array.map (fun f -> f.Trim())
+    |> Array.rev
+    |> Array.skipWhile String.IsNullOrEmpty
+    |> Array.rev
+
+  /// Parses a CSV record with columns of time (s) and acceleration (g).
+  /// Header rows are skipped and the time step must be constant.
+  let parseCsv (name: string) (text: string) : Result<GroundMotion, IOError> =
+    let rows =
+      lines text
+      |> List.map (fun line -> line, fields line |> Array.map tryParseFloat)
+      |> List.filter (fun (_, row) -> row.Length > 0)
+      |> List.skipWhile (snd >> Array.exists Option.isNone)
+
+    let valid (row: float option[]) =
+      row.Length >= 2 && row |> Array.forall Option.isSome
+
+    match rows |> List.tryFind (snd >> valid >> not), List.map snd rows with
+    | Some(line, _), _ ->
+      let msg = $"CSV row '{line.Trim()}' needs a time and an acceleration"
+      Error(DeserializationError msg)
+    | None, rows when rows.Length < 2 ->
+      Error(DeserializationError "CSV record needs at least two points")
+    | None, rows ->
+      let times = rows |> List.map (fun r -> r[0].Value)
+      let accelerations = rows |> List.map (fun r -> r[1].Value)
+      let dt = times[1] - times[0]
+
+      let uniform =
+        times
+        |> List.pairwise
+        |> List.forall (fun (a, b) -> abs (b - a - dt) <= 1e-6 * dt)
+
+      match dt, uniform with
+      | dt, _ when dt <= 0.0 -> Error(DeserializationError "Time must increase")
+      | _, false -> Error(DeserializationError "Time step must be constant")
+      | dt, true -> Ok(create name dt accelerations)
+
+  /// Reads a .AT2 or .csv record from disk, naming it after the file.
+  let read (f: FilePath) : Result<GroundMotion, IOError> =
+    let (FilePath path) = f
+    let name = Path.GetFileNameWithoutExtension path
+
+    match Path.GetExtension(path).ToLowerInvariant() with
+    | _ when not (File.Exists path) -> Error(PathError "File not found")
+    | ".at2" -> File.ReadAllText path |> parseAT2 name
+    | ".csv" -> File.ReadAllText path |> parseCsv name
+    | _ -> Error(FileExtensionError "Expected a .AT2 or .csv file")
+
+  /// Removes the least squares linear trend from the accelerations, so the
+  /// corrected record has no residual offset or drift.
+  let baselineCorrect (gm: GroundMotion) : GroundMotion =
+    match gm.Accelerations with
+    | [] -> gm
+    | accelerations ->
+      let t = List.init accelerations.Length float
+      let a = accelerations |> List.map float
+      let tMean, aMean = List.average t, List.average a
+      let stt = t |> List.sumBy (fun t -> (t - tMean) * (t - tMean))
+      let sta = List.map2 (fun t a -> (t - tMean) * (a - aMean)) t a
+      let slope = if stt > 0.0 then List.sum sta / stt else 0.0
+      let correct t a = (a - aMean - slope * (t - tMean)) * 1.0<m / s^2>
+
+      { gm with
+          Accelerations = List.map2 correct t a }
+
+  /// Resamples a record to a new time step by linear interpolation.
+  let resample
+    (dt: float<s>)
+    (gm: GroundMotion)
+    : Result<GroundMotion, IOError> =
+    match dt, gm.Accelerations with
+    | dt, _ when dt <= 0.0<s> ->
+      Error(DeserializationError "Time step must be > 0")
+    | _, [] -> Ok gm
+    | dt, accelerations ->
+      let a = Array.ofList accelerations
+      let last = a.Length - 1
+      let count = int (floor (duration gm / dt + 1e-9)) + 1
+
+      let at (t: float<s>) =
+        let x = t / gm.TimeStep
+        let i = int (floor x)
+
+        match i with
+        | i when i >= last -> a[last]
+        | i -> a[i] + (a[i + 1] - a[i]) * (x - float i)
+
+      Ok
+        { gm with
+            TimeStep = dt
+            Accelerations = List.init count (fun i -> at (float i * dt)) }
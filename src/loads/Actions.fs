@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Loads
+
+open Gazelle.Units
+
+/// <summary>
+/// Categories of use for imposed loads on floors (EN 1991-1-1 Table 6.1).
+/// </summary>
+type OccupancyCategory =
+  | A
+  | B
+  | C1
+  | C2
+  | C3
+  | C4
+  | C5
+  | D1
+  | D2
+  | E1
+
+/// <summary>
+/// Variable actions, used to label load cases and select their
+/// combination factors.
+/// </summary>
+type VariableAction =
+  | Imposed of OccupancyCategory
+  | Snow
+  | Wind
+
+/// <summary>
+/// Uniformly distributed load on a floor or roof surface.
+/// </summary>
+type AreaLoad =
+  { Label: string
+    Action: VariableAction
+    Load: float<kPa> }
+
+/// <summary>
+/// Arrangement of snow on a roof, with a load for each slope from left
+/// to right.
+/// </summary>
+type SnowCase = { Label: string; Slopes: AreaLoad list }
+
+[<RequireQualifiedAccess>]
+module OccupancyCategory =
+
+  /// <summary>
+  /// Name of a category, e.g. "C1".
+  /// </summary>
+  let name (c: OccupancyCategory) : string =
+    match c with
+    | A -> "A"
+    | B -> "B"
+    | C1 -> "C1"
+    | C2 -> "C2"
+    | C3 -> "C3"
+    | C4 -> "C4"
+    | C5 -> "C5"
+    | D1 -> "D1"
+    | D2 -> "D2"
+    | E1 -> "E1"
+
+[<RequireQualifiedAccess>]
+module VariableAction =
+
+  /// <summary>
+  /// Load case label for an action, e.g. "Imposed (B)".
+  /// </summary>
+  let label (a: VariableAction) : string =
+    match a with
+    | Imposed c -> $"Imposed ({OccupancyCategory.name c})"
+    | Snow -> "Snow"
+    | Wind -> "Wind"
@@ -0,0 +1,196 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Loads
+
+open System.Globalization
+
+/// <summary>
+/// Factors for the combination (ψ0), frequent (ψ1) and quasi-permanent
+/// (ψ2) values of a variable action.
+/// </summary>
+type CombinationFactors = { Psi0: float; Psi1: float; Psi2: float }
+
+/// <summary>
+/// Named load case. Variable cases of the same action are treated as
+/// alternatives, e.g. wind from different directions, so at most one of
+/// them appears in each combination.
+/// </summary>
+type LoadCase =
+  | PermanentCase of name: string
+  | VariableCase of
+    name: string *
+    action: VariableAction *
+    factors: CombinationFactors
+
+/// <summary>
+/// Factored combination of load cases.
+/// </summary>
+type Combination =
+  { Name: string
+    Factors: (string * float) list }
+
+[<RequireQualifiedAccess>]
+module LoadCase =
+
+  /// <summary>
+  /// Name of a load case.
+  /// </summary>
+  let name (c: LoadCase) : string =
+    match c with
+    | PermanentCase name
+    | VariableCase(name, _, _) -> name
+
+[<RequireQualifiedAccess>]
+module Combination =
+
+  /// <summary>
+  /// Describes a combination by its factors, e.g. "1.35 G + 1.5 Q".
+  /// </summary>
+  let describe (c: Combination) : string =
+    c.Factors
+    |> List.map (fun (name, factor) ->
+      let factor = factor.ToString(CultureInfo.InvariantCulture)
+      $"{factor} {name}")
+    |> String.concat " + "
+
+/// <summary>
+/// Combinations of actions to EN 1990. Partial factors are the Set B
+/// values of Table A1.2(B) with ξ from the UK National Annex, and
+/// permanent actions are taken as unfavourable throughout.
+/// </summary>
+[<RequireQualifiedAccess>]
+module EC0 =
+
+  let private γG = 1.35
+  let private γQ = 1.5
+  let private ξ = 0.925
+
+  /// <summary>
+  /// Combination factors for buildings (Table A1.1), assuming sites
+  /// below 1000m for snow.
+  /// </summary>
+  /// <param name="a">Variable action.</param>
+  /// <returns>Combination factors.</returns>
+  let factors (a: VariableAction) : CombinationFactors =
+    match a with
+    | Imposed A
+    | Imposed B -> { Psi0 = 0.7; Psi1 = 0.5; Psi2 = 0.3 }
+    | Imposed(C1 | C2 | C3 | C4 | C5 | D1 | D2) ->
+      { Psi0 = 0.7; Psi1 = 0.7; Psi2 = 0.6 }
+    | Imposed E1 -> { Psi0 = 1.0; Psi1 = 0.9; Psi2 = 0.8 }
+    | Snow -> { Psi0 = 0.5; Psi1 = 0.2; Psi2 = 0.0 }
+    | Wind -> { Psi0 = 0.5; Psi1 = 0.2; Psi2 = 0.0 }
+
+  /// <summary>
+  /// Creates a variable load case with the Table A1.1 factors.
+  /// </summary>
+  /// <param name="name">Load case name.</param>
+  /// <param name="a">Variable action.</param>
+  /// <returns>Load case.</returns>
+  let variable (name: string) (a: VariableAction) : LoadCase =
+    VariableCase(name, a, factors a)
+
+  /// <summary>
+  /// Lists every selection of variable cases taking at most one case of
+  /// each action, including selections that leave an action out.
+  /// </summary>
+  let private selections (cases: LoadCase list) =
+    cases
+    |> List.choose (fun c ->
+      match c with
+      | VariableCase(name, a, f) -> Some(name, a, f)
+      | PermanentCase _ -> None)
+    |> List.groupBy (fun (_, a, _) -> a)
+    |> List.fold
+      (fun acc (_, group) ->
+        [ for selection in acc do
+            selection
+
+            for c in group do
+              selection @ [ c ] ])
+      [ [] ]
+
+  /// <summary>
+  /// Expands load cases into combinations, taking each variable case in
+  /// turn as the leading action with the others accompanying it. Actions
+  /// are also left out in turn, as a favourable variable action is
+  /// omitted, e.g. imposed load under wind uplift. Duplicate
+  /// combinations and zero factors are removed.
+  /// </summary>
+  /// <param name="name">Prefix for the numbered combination names.</param>
+  /// <param name="permanent">Factor on permanent cases.</param>
+  /// <param name="leading">Factor on the leading variable case.</param>
+  /// <param name="accompanying">Factor on accompanying variable cases.</param>
+  /// <param name="cases">Load cases.</param>
+  /// <returns>Combinations.</returns>
+  let expand
+    (name: string)
+    (permanent: float)
+    (leading: CombinationFactors -> float)
+    (accompanying: CombinationFactors -> float)
+    (cases: LoadCase list)
+    : Combination list =
+    let permanents =
+      [ for c in cases do
+          match c with
+          | PermanentCase n -> n, permanent
+          | VariableCase _ -> () ]
+
+    let combine selection =
+      match selection with
+      | [] -> [ permanents ]
+      | selection ->
+        [ for lead, _, _ in selection do
+            permanents
+            @ [ for n, _, f in selection do
+                  n, (if n = lead then leading f else accompanying f) ] ]
+
+    selections cases
+    |> List.collect combine
+    |> List.map (List.filter (fun (_, factor) -> factor > 0.0))
+    |> List.distinct
+    |> List.mapi (fun i factors ->
+      { Name = $"{name} {i + 1}"
+        Factors = factors })
+
+  /// <summary>
+  /// Fundamental combinations to expression 6.10.
+  /// </summary>
+  let fundamental (cases: LoadCase list) : Combination list =
+    let q f = γQ * f.Psi0
+    cases |> expand "ULS 6.10" γG (fun _ -> γQ) q
+
+  /// <summary>
+  /// Fundamental combinations to expression 6.10a.
+  /// </summary>
+  let fundamentalA (cases: LoadCase list) : Combination list =
+    let q f = γQ * f.Psi0
+    cases |> expand "ULS 6.10a" γG q q
+
+  /// <summary>
+  /// Fundamental combinations to expression 6.10b.
+  /// </summary>
+  let fundamentalB (cases: LoadCase list) : Combination list =
+    let q f = γQ * f.Psi0
+    cases |> expand "ULS 6.10b" (ξ * γG) (fun _ -> γQ) q
+
+  /// <summary>
+  /// Characteristic combinations (6.14b).
+  /// </summary>
+  let characteristic (cases: LoadCase list) : Combination list =
+    cases |> expand "SLS Characteristic" 1.0 (fun _ -> 1.0) (fun f -> f.Psi0)
+
+  /// <summary>
+  /// Frequent combinations (6.15b).
+  /// </summary>
+  let frequent (cases: LoadCase list) : Combination list =
+    cases |> expand "SLS Frequent" 1.0 (fun f -> f.Psi1) (fun f -> f.Psi2)
+
+  /// <summary>
+  /// Quasi-permanent combinations (6.16b).
+  /// </summary>
+  let quasiPermanent (cases: LoadCase list) : Combination list =
+    let q f = f.Psi2
+    cases |> expand "SLS Quasi-permanent" 1.0 q q
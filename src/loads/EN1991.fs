@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Loads
+
+open Gazelle.Units
+open Gazelle.Design
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Actions on structures to EN 1991. Imposed loads use the recommended
+/// values of EN 1991-1-1 and snow loads follow EN 1991-1-3 with the
+/// UK National Annex characteristic ground snow load.
+/// </summary>
+[<RequireQualifiedAccess>]
+module EN1991 =
+
+  [<RequireQualifiedAccess>]
+  module Imposed =
+
+    /// <summary>
+    /// Characteristic uniformly distributed imposed load, qk (Table 6.2).
+    /// </summary>
+    /// <param name="c">Category of use.</param>
+    /// <returns>Imposed floor load.</returns>
+    let distributed (c: OccupancyCategory) : float<kPa> =
+      match c with
+      | A -> 2.0<kPa>
+      | B -> 3.0<kPa>
+      | C1 -> 3.0<kPa>
+      | C2 -> 4.0<kPa>
+      | C3 -> 5.0<kPa>
+      | C4 -> 5.0<kPa>
+      | C5 -> 5.0<kPa>
+      | D1 -> 4.0<kPa>
+      | D2 -> 5.0<kPa>
+      | E1 -> 7.5<kPa>
+
+    /// <summary>
+    /// Characteristic concentrated imposed load, Qk (Table 6.2).
+    /// </summary>
+    /// <param name="c">Category of use.</param>
+    /// <returns>Imposed point load.</returns>
+    let concentrated (c: OccupancyCategory) : float<kN> =
+      match c with
+      | A -> 2.0<kN>
+      | B -> 4.5<kN>
+      | C1 -> 4.0<kN>
+      | C2 -> 4.0<kN>
+      | C3 -> 4.0<kN>
+      | C4 -> 7.0<kN>
+      | C5 -> 4.5<kN>
+      | D1 -> 4.0<kN>
+      | D2 -> 7.0<kN>
+      | E1 -> 7.0<kN>
+
+    /// <summary>
+    /// Reduction factor for the loaded area, αA (6.3.1.2(10)), taking
+    /// ψ0 = 0.7 and A0 = 10m². Storage areas are not reduced.
+    /// </summary>
+    /// <param name="c">Category of use.</param>
+    /// <param name="area">Area supported by the member.</param>
+    /// <returns>Reduction factor.</returns>
+    let areaReduction (c: OccupancyCategory) (area: float<m^2>) : float =
+      let αA = min 1.0 (5.0 / 7.0 * 0.7 + 10.0<m^2> / area)
+
+      match c with
+      | E1 -> 1.0
+      | C1
+      | C2
+      | C3
+      | C4
+      | C5
+      | D1
+      | D2 -> max 0.6 αA
+      | A
+      | B -> αA
+
+    /// <summary>
+    /// Labelled imposed floor load for a category of use.
+    /// </summary>
+    /// <param name="c">Category of use.</param>
+    /// <returns>Area load.</returns>
+    let areaLoad (c: OccupancyCategory) : AreaLoad =
+      { Label = VariableAction.label (Imposed c)
+        Action = Imposed c
+        Load = distributed c }
+
+  [<RequireQualifiedAccess>]
+  module Snow =
+
+    let private γ = 2.0<kN / m^3>
+
+    /// <summary>
+    /// Characteristic ground snow load, sk (UK NA, Figure NA.1).
+    /// </summary>
+    /// <param name="zone">Zone number from the UK snow map.</param>
+    /// <param name="altitude">Site altitude above sea level.</param>
+    /// <returns>Ground snow load.</returns>
+    let characteristic (zone: float) (altitude: float<m>) : float<kPa> =
+      let sk = 0.15 + (0.1 * zone + 0.05) + (float altitude - 100.0) / 525.0
+      sk * 1.0<kPa>
+
+    /// <summary>
+    /// Shape coefficient for a monopitch or duopitch roof slope, μ1
+    /// (Table 5.2), assuming snow is free to slide off the roof.
+    /// </summary>
+    /// <param name="α">Pitch of the roof slope.</param>
+    /// <returns>Shape coefficient.</returns>
+    let μ1 (α: float<deg>) : float =
+      match RemoveUnits.fromFloat α with
+      | α when α <= 30.0 -> 0.8
+      | α when α < 60.0 -> 0.8 * (60.0 - α) / 30.0
+      | _ -> 0.0
+
+    /// <summary>
+    /// Shape coefficient in the valley of a multi-span roof, μ2
+    /// (Table 5.2), using the mean pitch of the adjacent slopes.
+    /// </summary>
+    /// <param name="α">Mean pitch of the adjacent slopes.</param>
+    /// <returns>Shape coefficient.</returns>
+    let μ2 (α: float<deg>) : float =
+      match RemoveUnits.fromFloat α with
+      | α when α <= 30.0 -> 0.8 + 0.8 * α / 30.0
+      | α when α < 60.0 -> 1.6
+      | _ -> 0.0
+
+    /// <summary>
+    /// Snow load on a roof, s = μi Ce Ct sk (5.2(3)).
+    /// </summary>
+    /// <param name="μ">Shape coefficient.</param>
+    /// <param name="ce">Exposure coefficient.</param>
+    /// <param name="ct">Thermal coefficient.</param>
+    /// <param name="sk">Characteristic ground snow load.</param>
+    /// <returns>Roof snow load.</returns>
+    let roofLoad (μ: float) (ce: float) (ct: float) (sk: float<kPa>) =
+      μ * ce * ct * sk
+
+    let private slope label (load: float<kPa>) : AreaLoad =
+      { Label = label
+        Action = Snow
+        Load = load }
+
+    /// <summary>
+    /// Undrifted snow on a monopitch roof with Ce = Ct = 1.0.
+    /// </summary>
+    /// <param name="sk">Characteristic ground snow load.</param>
+    /// <param name="α">Pitch of the roof.</param>
+    /// <returns>Snow load cases.</returns>
+    let monopitch (sk: float<kPa>) (α: float<deg>) : SnowCase list =
+      [ { Label = "Snow"
+          Slopes = [ slope "Snow" (roofLoad (μ1 α) 1.0 1.0 sk) ] } ]
+
+    /// <summary>
+    /// Undrifted and drifted snow on a duopitch roof with Ce = Ct = 1.0
+    /// (Figure 5.3). Drifted cases halve the load on one slope.
+    /// </summary>
+    /// <param name="sk">Characteristic ground snow load.</param>
+    /// <param name="α1">Pitch of the left slope.</param>
+    /// <param name="α2">Pitch of the right slope.</param>
+    /// <returns>Snow load cases.</returns>
+    let duopitch
+      (sk: float<kPa>)
+      (α1: float<deg>)
+      (α2: float<deg>)
+      : SnowCase list =
+      let left = roofLoad (μ1 α1) 1.0 1.0 sk
+      let right = roofLoad (μ1 α2) 1.0 1.0 sk
+
+      let case label l r : SnowCase =
+        { Label = label
+          Slopes = [ slope $"{label} left" l; slope $"{label} right" r ] }
+
+      [ case "Snow (i)" left right
+        case "Snow (ii)" (0.5 * left) right
+        case "Snow (iii)" left (0.5 * right) ]
+
+    /// <summary>
+    /// Peak shape coefficient and length of a drift against a taller
+    /// construction (EN 1991-1-3 5.3.6), μ2 = μs + μw and ls from
+    /// expressions (5.7) to (5.9), using the recommended limits
+    /// 0.8 ≤ μw ≤ 4.0 and 5m ≤ ls ≤ 15m.
+    /// </summary>
+    /// <param name="sk">Characteristic ground snow load.</param>
+    /// <param name="b1">Width of the upper roof.</param>
+    /// <param name="b2">Width of the lower roof.</param>
+    /// <param name="h">Height of the step between the roofs.</param>
+    /// <param name="α">Pitch of the upper roof.</param>
+    /// <returns>Peak shape coefficient and drift length, or an error.</returns>
+    let drift
+      (sk: float<kPa>)
+      (b1: float<m>)
+      (b2: float<m>)
+      (h: float<m>)
+      (α: float<deg>)
+      : Result<float * float<m>, DesignError> =
+      match sk, b1, b2, h with
+      | sk, _, _, _ when sk <= 0.0<kPa> ->
+        Error(InvalidInput "Ground snow load must be > 0")
+      | _, b1, _, _ when b1 < 0.0<m> ->
+        Error(InvalidInput "Upper roof width b1 must be >= 0")
+      | _, _, b2, h when b2 <= 0.0<m> || h <= 0.0<m> ->
+        Error(InvalidInput "Lower roof width b2 and step h must be > 0")
+      | _ ->
+        let μs =
+          match RemoveUnits.fromFloat α with
+          | α when α <= 15.0 -> 0.0
+          | _ -> 0.5 * μ1 α
+
+        let μw = (b1 + b2) / (2.0 * h) |> min (γ * h / sk) |> min 4.0
+        let ls = 2.0 * h |> max 5.0<m> |> min 15.0<m>
+        Ok(μs + max 0.8 μw, ls)
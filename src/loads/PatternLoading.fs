@@ -0,0 +1,144 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Loads
+
+open Gazelle.Units
+open Gazelle.Design
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Arrangement of variable load with a flag for each span from left to
+/// right.
+/// </summary>
+type LoadPattern = { Name: string; Loaded: bool list }
+
+/// <summary>
+/// Envelope of bending moment and shear at a station along a span.
+/// Sagging moments are positive. Span numbers start at 1.
+/// </summary>
+type EnvelopePoint =
+  { Span: int
+    Position: float<m>
+    MaxMoment: float<kNm>
+    MinMoment: float<kNm>
+    MaxShear: float<kN>
+    MinShear: float<kN> }
+
+/// <summary>
+/// Pattern loading of continuous beams on knife-edge supports with a
+/// uniform flexural stiffness, as required by EN 1992-1-1 5.1.3.
+/// </summary>
+[<RequireQualifiedAccess>]
+module PatternLoading =
+
+  let private stations = 10
+
+  /// <summary>
+  /// Generates load patterns for a continuous beam: all spans loaded,
+  /// alternate spans loaded and each pair of adjacent spans loaded.
+  /// </summary>
+  /// <param name="spans">Number of spans.</param>
+  /// <returns>Distinct load patterns.</returns>
+  let patterns (spans: int) : LoadPattern list =
+    let pattern name loaded : LoadPattern =
+      { Name = name
+        Loaded = List.init spans loaded }
+
+    [ pattern "All spans" (fun _ -> true)
+      if spans > 1 then
+        pattern "Odd spans" (fun i -> i % 2 = 0)
+        pattern "Even spans" (fun i -> i % 2 = 1)
+      for i in 1 .. spans - 1 do
+        pattern $"Spans {i} and {i + 1}" (fun j -> j = i - 1 || j = i) ]
+    |> List.distinctBy (fun p -> p.Loaded)
+
+  /// <summary>
+  /// Support moments of a continuous beam under uniform load on each
+  /// span, solving the equation of three moments. End supports are
+  /// pinned, so the first and last moments are zero.
+  /// </summary>
+  /// <param name="spans">Span lengths.</param>
+  /// <param name="loads">Uniform load on each span.</param>
+  /// <returns>Moment at each support from left to right.</returns>
+  let supportMoments
+    (spans: float<m> list)
+    (loads: float<kNpm> list)
+    : float<kNm> list =
+    let l = spans |> List.map float |> Array.ofList
+    let w = loads |> List.map float |> Array.ofList
+    let n = l.Length - 1
+
+    // Tridiagonal system for the interior supports (Thomas algorithm).
+    let a = Array.init n (fun i -> l[i])
+    let b = Array.init n (fun i -> 2.0 * (l[i] + l[i + 1]))
+    let c = Array.init n (fun i -> l[i + 1])
+
+    let d =
+      Array.init n (fun i ->
+        let cube x = x * x * x
+        -(w[i] * cube (l[i]) + w[i + 1] * cube (l[i + 1])) / 4.0)
+
+    for i in 1 .. n - 1 do
+      let k = a[i] / b[i - 1]
+      b[i] <- b[i] - k * c[i - 1]
+      d[i] <- d[i] - k * d[i - 1]
+
+    let x = Array.zeroCreate n
+
+    for i in n - 1 .. -1 .. 0 do
+      let next = if i < n - 1 then c[i] * x[i + 1] else 0.0
+      x[i] <- (d[i] - next) / b[i]
+
+    [ 0.0; yield! x; 0.0 ] |> List.map (fun m -> m * 1.0<kNm>)
+
+  /// <summary>
+  /// Envelope of moments and shears over all load patterns. Unloaded
+  /// spans carry the permanent load only, e.g. 1.35Gk, while loaded
+  /// spans carry the full load, e.g. 1.35Gk + 1.5Qk.
+  /// </summary>
+  /// <param name="spans">Span lengths.</param>
+  /// <param name="unloaded">Load on spans without variable load.</param>
+  /// <param name="loaded">Load on spans with variable load.</param>
+  /// <returns>Envelope at stations along each span, or an error.</returns>
+  let envelope
+    (spans: float<m> list)
+    (unloaded: float<kNpm>)
+    (loaded: float<kNpm>)
+    : Result<EnvelopePoint list, DesignError> =
+    match spans with
+    | [] -> Error(InvalidInput "Beam has no spans")
+    | spans when spans |> List.exists (fun l -> l <= 0.0<m>) ->
+      Error(InvalidInput "Span lengths must be > 0")
+    | spans ->
+      let results =
+        [ for p in patterns spans.Length do
+            let loads =
+              p.Loaded |> List.map (fun on -> if on then loaded else unloaded)
+
+            let moments = supportMoments spans loads |> Array.ofList
+
+            [ for i, (l, w) in List.indexed (List.zip spans loads) do
+                for s in 0..stations do
+                  let x = l * float s / float stations
+                  let left, right = moments[i], moments[i + 1]
+                  let free = w * x * (l - x) / 2.0
+                  let moment = left + (right - left) * x / l + free
+                  let shear = w * (l / 2.0 - x) + (right - left) / l
+                  (i + 1, x), (moment, shear) ] ]
+
+      results
+      |> List.concat
+      |> List.groupBy fst
+      |> List.map (fun ((span, x), values) ->
+        let moments = values |> List.map (snd >> fst)
+        let shears = values |> List.map (snd >> snd)
+
+        { Span = span
+          Position = x
+          MaxMoment = List.max moments
+          MinMoment = List.min moments
+          MaxShear = List.max shears
+          MinShear = List.min shears })
+      |> Ok
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Materials
+
+open Gazelle.Units
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Linear-elastic orthotropic lamina in plane stress. Direction 1 is
+/// along the fibres and direction 2 is transverse to them.
+/// </summary>
+type Orthotropic =
+  { E1: float<MPa>
+    E2: float<MPa>
+    G12: float<MPa>
+    Nu12: float }
+
+/// <summary>
+/// Single ply of a laminate with its fibre angle measured from the
+/// laminate x-axis.
+/// </summary>
+type Ply =
+  { Material: Orthotropic
+    Thickness: float<mm>
+    Angle: float<deg> }
+
+/// <summary>
+/// Laminate stiffness matrices relating in-plane forces and moments per
+/// unit width to mid-plane strains and curvatures.
+/// </summary>
+type ABD =
+  { A: float<N / mm>[,]
+    B: float<N>[,]
+    D: float<Nmm>[,] }
+
+type MaterialError = InvalidMaterial of string
+
+[<RequireQualifiedAccess>]
+module Orthotropic =
+
+  /// <summary>
+  /// Creates an orthotropic lamina, checking that its compliance matrix
+  /// is positive definite.
+  /// </summary>
+  /// <param name="e1">Longitudinal elastic modulus.</param>
+  /// <param name="e2">Transverse elastic modulus.</param>
+  /// <param name="g12">In-plane shear modulus.</param>
+  /// <param name="nu12">Major Poisson's ratio.</param>
+  /// <returns>Orthotropic lamina, or an error.</returns>
+  let tryCreate
+    (e1: float<MPa>)
+    (e2: float<MPa>)
+    (g12: float<MPa>)
+    (nu12: float)
+    : Result<Orthotropic, MaterialError> =
+    match e1, e2, g12 with
+    | e1, e2, g12 when e1 <= 0.0<MPa> || e2 <= 0.0<MPa> || g12 <= 0.0<MPa> ->
+      Error(InvalidMaterial "Moduli must be > 0")
+    | e1, e2, _ when nu12 * nu12 >= e1 / e2 ->
+      Error(InvalidMaterial "Poisson's ratio must satisfy ν12² < E1/E2")
+    | _ ->
+      Ok
+        { E1 = e1
+          E2 = e2
+          G12 = g12
+          Nu12 = nu12 }
+
+  /// <summary>
+  /// Minor Poisson's ratio, ν21 = ν12 E2 / E1.
+  /// </summary>
+  let nu21 (m: Orthotropic) : float = m.Nu12 * m.E2 / m.E1
+
+  /// <summary>
+  /// Reduced stiffness matrix [Q] in the material axes.
+  /// </summary>
+  /// <param name="m">Orthotropic lamina.</param>
+  /// <returns>3x3 plane stress constitutive matrix.</returns>
+  let reducedStiffness (m: Orthotropic) : float<MPa>[,] =
+    let denominator = 1.0 - m.Nu12 * nu21 m
+    let q11 = m.E1 / denominator
+    let q22 = m.E2 / denominator
+    let q12 = m.Nu12 * m.E2 / denominator
+
+    array2D
+      [ [ q11; q12; 0.0<MPa> ]
+        [ q12; q22; 0.0<MPa> ]
+        [ 0.0<MPa>; 0.0<MPa>; m.G12 ] ]
+
+  /// <summary>
+  /// Transformed reduced stiffness matrix [Q̄] for a lamina rotated by
+  /// the given angle from the laminate x-axis.
+  /// </summary>
+  /// <param name="m">Orthotropic lamina.</param>
+  /// <param name="angle">Fibre angle.</param>
+  /// <returns>3x3 plane stress constitutive matrix in laminate axes.</returns>
+  let transformedStiffness
+    (m: Orthotropic)
+    (angle: float<deg>)
+    : float<MPa>[,] =
+    let q = reducedStiffness m
+    let q11, q12, q22, q66 = q[0, 0], q[0, 1], q[1, 1], q[2, 2]
+    let θ = Convert.degreesToRadians angle |> RemoveUnits.fromFloat
+    let c, s = cos θ, sin θ
+    let c2, s2 = c * c, s * s
+
+    let qb11 = q11 * c2 * c2 + 2.0 * (q12 + 2.0 * q66) * s2 * c2 + q22 * s2 * s2
+    let qb22 = q11 * s2 * s2 + 2.0 * (q12 + 2.0 * q66) * s2 * c2 + q22 * c2 * c2
+
+    let qb12 =
+      (q11 + q22 - 4.0 * q66) * s2 * c2 + q12 * (s2 * s2 + c2 * c2)
+
+    let qb16 =
+      (q11 - q12 - 2.0 * q66) * s * c2 * c
+      + (q12 - q22 + 2.0 * q66) * s2 * s * c
+
+    let qb26 =
+      (q11 - q12 - 2.0 * q66) * s2 * s * c
+      + (q12 - q22 + 2.0 * q66) * s * c2 * c
+
+    let qb66 =
+      (q11 + q22 - 2.0 * q12 - 2.0 * q66) * s2 * c2
+      + q66 * (s2 * s2 + c2 * c2)
+
+    array2D
+      [ [ qb11; qb12; qb16 ]
+        [ qb12; qb22; qb26 ]
+        [ qb16; qb26; qb66 ] ]
+
+[<RequireQualifiedAccess>]
+module Laminate =
+
+  /// <summary>
+  /// Mirrors a stack of plies about the mid-plane, e.g. [0/45] becomes
+  /// [0/45/45/0].
+  /// </summary>
+  /// <param name="plies">Plies from the bottom face to the mid-plane.</param>
+  /// <returns>Symmetric layup.</returns>
+  let symmetric (plies: Ply list) : Ply list = plies @ List.rev plies
+
+  /// <summary>
+  /// Total thickness of a layup.
+  /// </summary>
+  let thickness (plies: Ply list) : float<mm> =
+    plies |> List.sumBy (fun p -> p.Thickness)
+
+  /// <summary>
+  /// Calculates the A, B and D stiffness matrices of a layup using
+  /// classical lamination theory. Plies are listed from the bottom face.
+  /// </summary>
+  /// <param name="plies">Plies from the bottom face to the top face.</param>
+  /// <returns>Laminate stiffness matrices, or an error.</returns>
+  let tryAbd (plies: Ply list) : Result<ABD, MaterialError> =
+    match plies with
+    | [] -> Error(InvalidMaterial "Laminate has no plies")
+    | plies when plies |> List.exists (fun p -> p.Thickness <= 0.0<mm>) ->
+      Error(InvalidMaterial "Ply thickness must be > 0")
+    | plies ->
+      let a = Array2D.zeroCreate<float<N / mm>> 3 3
+      let b = Array2D.zeroCreate<float<N>> 3 3
+      let d = Array2D.zeroCreate<float<Nmm>> 3 3
+      let mutable z0 = -(thickness plies) / 2.0
+
+      for ply in plies do
+        let qb = Orthotropic.transformedStiffness ply.Material ply.Angle
+        let z1 = z0 + ply.Thickness
+
+        for i in 0..2 do
+          for j in 0..2 do
+            a[i, j] <- a[i, j] + qb[i, j] * (z1 - z0)
+            b[i, j] <- b[i, j] + qb[i, j] * (z1 * z1 - z0 * z0) / 2.0
+            d[i, j] <- d[i, j] + qb[i, j] * (z1 * z1 * z1 - z0 * z0 * z0) / 3.0
+
+        z0 <- z1
+
+      Ok { A = a; B = b; D = d }
+
+[<RequireQualifiedAccess>]
+module MaterialError =
+
+  let getAsString (e: MaterialError) : string =
+    match e with
+    | InvalidMaterial msg -> $"Invalid Material: {msg}."
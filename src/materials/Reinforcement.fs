@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Materials
+
+open Gazelle.Units
+
+/// <summary>
+/// Ductility classes of reinforcing steel (EN 1992-1-1 Annex C).
+/// </summary>
+type DuctilityClass =
+  | ClassA
+  | ClassB
+  | ClassC
+
+/// <summary>
+/// Reinforcing steel grades to BS 4449, named by yield strength and
+/// ductility class.
+/// </summary>
+type ReinforcementGrade =
+  | B500A
+  | B500B
+  | B500C
+
+/// <summary>
+/// Mechanical properties of reinforcing steel.
+/// </summary>
+type Reinforcement =
+  { Grade: ReinforcementGrade
+    Fyk: float<MPa>
+    ElasticModulus: float<MPa>
+    Ductility: DuctilityClass }
+
+/// <summary>
+/// Relaxation classes of prestressing steel (EN 1992-1-1 3.3.2).
+/// Class 1 is wire or strand with ordinary relaxation, Class 2 is wire
+/// or strand with low relaxation and Class 3 is hot rolled bar.
+/// </summary>
+type RelaxationClass =
+  | Class1Relaxation
+  | Class2Relaxation
+  | Class3Relaxation
+
+/// <summary>
+/// Seven-wire prestressing strand, e.g. "Y1860S7 15.7".
+/// </summary>
+type PrestressingStrand =
+  { Designation: string
+    Diameter: float<mm>
+    Area: float<mm^2>
+    Fpk: float<MPa>
+    Fp01k: float<MPa>
+    ElasticModulus: float<MPa>
+    Relaxation: RelaxationClass }
+
+[<RequireQualifiedAccess>]
+module DuctilityClass =
+
+  /// <summary>
+  /// Minimum characteristic ratio of tensile to yield strength, k.
+  /// </summary>
+  let k (c: DuctilityClass) : float =
+    match c with
+    | ClassA -> 1.05
+    | ClassB -> 1.08
+    | ClassC -> 1.15
+
+  /// <summary>
+  /// Characteristic strain at maximum force, εuk.
+  /// </summary>
+  let ultimateStrain (c: DuctilityClass) : float =
+    match c with
+    | ClassA -> 0.025
+    | ClassB -> 0.05
+    | ClassC -> 0.075
+
+[<RequireQualifiedAccess>]
+module Reinforcement =
+
+  /// <summary>
+  /// Creates reinforcement of a standard grade with Es = 200 GPa.
+  /// </summary>
+  /// <param name="grade">Reinforcement grade.</param>
+  /// <returns>Reinforcement.</returns>
+  let ofGrade (grade: ReinforcementGrade) : Reinforcement =
+    let ductility =
+      match grade with
+      | B500A -> ClassA
+      | B500B -> ClassB
+      | B500C -> ClassC
+
+    { Grade = grade
+      Fyk = 500.0<MPa>
+      ElasticModulus = 200_000.0<MPa>
+      Ductility = ductility }
+
+  /// <summary>
+  /// Characteristic yield strain, fyk / Es.
+  /// </summary>
+  let yieldStrain (r: Reinforcement) : float = r.Fyk / r.ElasticModulus
+
+  /// <summary>
+  /// Characteristic tensile strength, k fyk.
+  /// </summary>
+  let tensileStrength (r: Reinforcement) : float<MPa> =
+    DuctilityClass.k r.Ductility * r.Fyk
+
+  /// <summary>
+  /// Design yield strength, fyd = fyk / γs.
+  /// </summary>
+  /// <param name="γs">Partial factor for reinforcing steel.</param>
+  /// <param name="r">Reinforcement.</param>
+  /// <returns>Design yield strength.</returns>
+  let designStrength (γs: float) (r: Reinforcement) : float<MPa> = r.Fyk / γs
+
+[<RequireQualifiedAccess>]
+module PrestressingStrand =
+
+  let private strand designation diameter area fpk : PrestressingStrand =
+    { Designation = designation
+      Diameter = diameter
+      Area = area
+      Fpk = fpk
+      Fp01k = 0.88 * fpk
+      ElasticModulus = 195_000.0<MPa>
+      Relaxation = Class2Relaxation }
+
+  /// <summary>
+  /// Low relaxation 12.5mm seven-wire strand to EN 10138-3.
+  /// </summary>
+  let y1860S7_12_5 = strand "Y1860S7 12.5" 12.5<mm> 93.0<mm^2> 1860.0<MPa>
+
+  /// <summary>
+  /// Low relaxation 15.2mm seven-wire strand to EN 10138-3.
+  /// </summary>
+  let y1860S7_15_2 = strand "Y1860S7 15.2" 15.2<mm> 139.0<mm^2> 1860.0<MPa>
+
+  /// <summary>
+  /// Low relaxation 15.7mm seven-wire strand to EN 10138-3.
+  /// </summary>
+  let y1860S7_15_7 = strand "Y1860S7 15.7" 15.7<mm> 150.0<mm^2> 1860.0<MPa>
+
+  /// <summary>
+  /// Characteristic breaking load, Fpk Ap.
+  /// </summary>
+  let breakingLoad (s: PrestressingStrand) : float<kN> =
+    s.Fpk * s.Area |> Convert.newtonsToKilonewtons
+
+  /// <summary>
+  /// Relaxation loss after a given time (EN 1992-1-1 3.3.2(7)), using the
+  /// default 1000 hour relaxation ρ1000 for each class.
+  /// </summary>
+  /// <param name="s">Prestressing strand.</param>
+  /// <param name="σpi">Initial prestress.</param>
+  /// <param name="hours">Time after tensioning in hours.</param>
+  /// <returns>Loss of prestress, Δσpr.</returns>
+  let relaxationLoss
+    (s: PrestressingStrand)
+    (σpi: float<MPa>)
+    (hours: float)
+    : float<MPa> =
+    let μ = σpi / s.Fpk
+    let time = (hours / 1000.0) ** (0.75 * (1.0 - μ))
+
+    let ratio =
+      match s.Relaxation with
+      | Class1Relaxation -> 5.39 * 8.0 * exp (6.7 * μ)
+      | Class2Relaxation -> 0.66 * 2.5 * exp (9.1 * μ)
+      | Class3Relaxation -> 1.98 * 4.0 * exp (8.0 * μ)
+
+    ratio * time * 1e-5 * σpi
@@ -0,0 +1,464 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Steel
+
+open Gazelle.Units
+open Gazelle.Design
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Cross-section classification to EN 1993-1-1 Table 5.2.
+/// </summary>
+type SectionClass =
+  | Class1
+  | Class2
+  | Class3
+  | Class4
+
+/// <summary>
+/// Imperfection curves to EN 1993-1-1 Table 6.1.
+/// </summary>
+type BucklingCurve =
+  | CurveA0
+  | CurveA
+  | CurveB
+  | CurveC
+  | CurveD
+
+/// <summary>
+/// Member design checks to EN 1993-1-1 for doubly-symmetric rolled
+/// I and H sections. Partial factors follow the UK National Annex.
+/// </summary>
+[<RequireQualifiedAccess>]
+module EC3 =
+
+  let private γM0 = 1.0
+  let private γM1 = 1.0
+
+  let private toKilonewtons (x: float<N>) = Convert.newtonsToKilonewtons x
+
+  let private toKilonewtonMetres (x: float<Nmm>) =
+    Convert.newtonMillimetresToKilonewtonMetres x
+
+  /// <summary>
+  /// Material coefficient ε = √(235 / fy).
+  /// </summary>
+  /// <param name="fy">Yield strength.</param>
+  /// <returns>Material coefficient.</returns>
+  let epsilon (fy: float<MPa>) : float = sqrt (235.0<MPa> / fy)
+
+  [<RequireQualifiedAccess>]
+  module Classification =
+
+    let private classify (ratio: float) (limits: float * float * float) =
+      let class1, class2, class3 = limits
+
+      match ratio with
+      | r when r <= class1 -> Class1
+      | r when r <= class2 -> Class2
+      | r when r <= class3 -> Class3
+      | _ -> Class4
+
+    /// <summary>
+    /// Classifies an outstand flange in compression.
+    /// </summary>
+    /// <param name="s">I or H section.</param>
+    /// <param name="fy">Yield strength.</param>
+    /// <returns>Flange class.</returns>
+    let flange (s: ISection) (fy: float<MPa>) : SectionClass =
+      let ε = epsilon fy
+      let c = (s.Width - s.WebThickness - 2.0 * s.RootRadius) / 2.0
+      classify (c / s.FlangeThickness) (9.0 * ε, 10.0 * ε, 14.0 * ε)
+
+    /// <summary>
+    /// Classifies an internal web subject to bending and axial force.
+    /// Axial tension is conservatively ignored.
+    /// </summary>
+    /// <param name="s">I or H section.</param>
+    /// <param name="fy">Yield strength.</param>
+    /// <param name="axial">Axial force, compression positive.</param>
+    /// <returns>Web class.</returns>
+    let web (s: ISection) (fy: float<MPa>) (axial: float<kN>) : SectionClass =
+      let ε = epsilon fy
+      let c = s.Depth - 2.0 * s.FlangeThickness - 2.0 * s.RootRadius
+      let n = Convert.kilonewtonsToNewtons (max axial 0.0<kN>)
+      let α = min 1.0 (0.5 + n / (2.0 * c * s.WebThickness * fy))
+      let ψ = max (-1.0) (min 1.0 (2.0 * n / (s.Area * fy) - 1.0))
+      let class1 = 396.0 * ε / (13.0 * α - 1.0)
+      let class2 = 456.0 * ε / (13.0 * α - 1.0)
+
+      let class3 =
+        match ψ with
+        | ψ when ψ > -1.0 -> 42.0 * ε / (0.67 + 0.33 * ψ)
+        | ψ -> 62.0 * ε * (1.0 - ψ) * sqrt (-ψ)
+
+      classify (c / s.WebThickness) (class1, class2, class3)
+
+    /// <summary>
+    /// Classifies a cross-section as its least favourable element.
+    /// </summary>
+    /// <param name="s">I or H section.</param>
+    /// <param name="fy">Yield strength.</param>
+    /// <param name="axial">Axial force, compression positive.</param>
+    /// <returns>Section class.</returns>
+    let section
+      (s: ISection)
+      (fy: float<MPa>)
+      (axial: float<kN>)
+      : SectionClass =
+      max (flange s fy) (web s fy axial)
+
+  [<RequireQualifiedAccess>]
+  module Resistance =
+
+    /// <summary>
+    /// Design plastic resistance to tension, Npl,Rd (6.6).
+    /// </summary>
+    let tension (s: ISection) (m: SteelMaterial) : float<kN> =
+      s.Area * m.YieldStrength / γM0 |> toKilonewtons
+
+    /// <summary>
+    /// Design resistance to uniform compression, Nc,Rd (6.10).
+    /// </summary>
+    let compression (s: ISection) (m: SteelMaterial) : float<kN> =
+      s.Area * m.YieldStrength / γM0 |> toKilonewtons
+
+    /// <summary>
+    /// Shear area for load parallel to the web of a rolled I or H section.
+    /// </summary>
+    let shearArea (s: ISection) : float<mm^2> =
+      let hw = s.Depth - 2.0 * s.FlangeThickness
+
+      let av =
+        s.Area - 2.0 * s.Width * s.FlangeThickness
+        + (s.WebThickness + 2.0 * s.RootRadius) * s.FlangeThickness
+
+      max av (hw * s.WebThickness)
+
+    /// <summary>
+    /// Design plastic shear resistance parallel to the web, Vpl,Rd (6.18).
+    /// </summary>
+    let shear (s: ISection) (m: SteelMaterial) : float<kN> =
+      shearArea s * (m.YieldStrength / sqrt 3.0) / γM0 |> toKilonewtons
+
+    /// <summary>
+    /// Section modulus appropriate to the section class.
+    /// </summary>
+    let private modulus (c: SectionClass) (plastic: float<mm^3>) elastic =
+      match c with
+      | Class1
+      | Class2 -> plastic
+      | Class3
+      | Class4 -> elastic
+
+    /// <summary>
+    /// Design resistance for major axis bending, Mc,y,Rd (6.13/6.14).
+    /// </summary>
+    let bendingY (c: SectionClass) (s: ISection) (m: SteelMaterial) =
+      let w = modulus c s.PlasticModulusY s.ElasticModulusY
+      w * m.YieldStrength / γM0 |> toKilonewtonMetres
+
+    /// <summary>
+    /// Design resistance for minor axis bending, Mc,z,Rd (6.13/6.14).
+    /// </summary>
+    let bendingZ (c: SectionClass) (s: ISection) (m: SteelMaterial) =
+      let w = modulus c s.PlasticModulusZ s.ElasticModulusZ
+      w * m.YieldStrength / γM0 |> toKilonewtonMetres
+
+    /// <summary>
+    /// Major axis bending resistance reduced for high shear (6.2.8).
+    /// </summary>
+    let bendingYWithShear
+      (c: SectionClass)
+      (s: ISection)
+      (m: SteelMaterial)
+      (shearForce: float<kN>)
+      : float<kNm> =
+      let vplRd = shear s m
+      let mcRd = bendingY c s m
+
+      match abs shearForce / vplRd with
+      | ratio when ratio <= 0.5 -> mcRd
+      | ratio ->
+        let ρ = (2.0 * ratio - 1.0) ** 2.0
+
+        match c with
+        | Class1
+        | Class2 ->
+          let hw = s.Depth - 2.0 * s.FlangeThickness
+          let aw = hw * s.WebThickness
+          let w = s.PlasticModulusY - ρ * aw * aw / (4.0 * s.WebThickness)
+          min mcRd (w * m.YieldStrength / γM0 |> toKilonewtonMetres)
+        | Class3
+        | Class4 -> mcRd * (1.0 - ρ)
+
+  [<RequireQualifiedAccess>]
+  module Buckling =
+
+    /// <summary>
+    /// Imperfection factor α for a given buckling curve (Table 6.1).
+    /// </summary>
+    let imperfection (c: BucklingCurve) : float =
+      match c with
+      | CurveA0 -> 0.13
+      | CurveA -> 0.21
+      | CurveB -> 0.34
+      | CurveC -> 0.49
+      | CurveD -> 0.76
+
+    /// <summary>
+    /// Flexural buckling curves for rolled I sections in S235 to S420
+    /// (Table 6.2). Returns the curves for the major and minor axes.
+    /// </summary>
+    let flexuralCurves (s: ISection) : BucklingCurve * BucklingCurve =
+      match s.Depth / s.Width, s.FlangeThickness with
+      | ratio, tf when ratio > 1.2 && tf <= 40.0<mm> -> CurveA, CurveB
+      | ratio, tf when ratio > 1.2 && tf <= 100.0<mm> -> CurveB, CurveC
+      | ratio, tf when ratio <= 1.2 && tf <= 100.0<mm> -> CurveB, CurveC
+      | _ -> CurveD, CurveD
+
+    /// <summary>
+    /// Lateral torsional buckling curve for rolled I sections (Table 6.5).
+    /// </summary>
+    let lateralTorsionalCurve (s: ISection) : BucklingCurve =
+      match s.Depth / s.Width with
+      | ratio when ratio <= 2.0 -> CurveB
+      | _ -> CurveC
+
+    /// <summary>
+    /// Reduction factor χ for flexural buckling (6.49).
+    /// </summary>
+    /// <param name="α">Imperfection factor.</param>
+    /// <param name="λ">Non-dimensional slenderness.</param>
+    /// <returns>Reduction factor.</returns>
+    let reductionFactor (α: float) (λ: float) : float =
+      let φ = 0.5 * (1.0 + α * (λ - 0.2) + λ * λ)
+      min 1.0 (1.0 / (φ + sqrt (φ * φ - λ * λ)))
+
+    /// <summary>
+    /// Reduction factor χLT for lateral torsional buckling of rolled
+    /// sections (6.57) with λLT,0 = 0.4 and β = 0.75.
+    /// </summary>
+    /// <param name="α">Imperfection factor.</param>
+    /// <param name="λ">Non-dimensional slenderness.</param>
+    /// <returns>Reduction factor.</returns>
+    let lateralTorsionalReductionFactor (α: float) (λ: float) : float =
+      let φ = 0.5 * (1.0 + α * (λ - 0.4) + 0.75 * λ * λ)
+      let χ = 1.0 / (φ + sqrt (φ * φ - 0.75 * λ * λ))
+      List.min [ 1.0; χ; 1.0 / (λ * λ) ]
+
+    /// <summary>
+    /// Elastic critical force for flexural buckling.
+    /// </summary>
+    let criticalForce
+      (m: SteelMaterial)
+      (i: float<mm^4>)
+      (length: float<mm>)
+      : float<N> =
+      System.Math.PI ** 2.0 * m.ElasticModulus * i / (length * length)
+
+    /// <summary>
+    /// Elastic critical moment for lateral torsional buckling of a
+    /// doubly-symmetric section with the load applied at the shear centre.
+    /// </summary>
+    let criticalMoment (mem: SteelMember) : float<Nmm> =
+      let s = mem.Section
+      let e = mem.Material.ElasticModulus
+      let g = mem.Material.ShearModulus
+      let l = mem.BucklingLengths.LateralTorsional
+      let π2EIz = System.Math.PI ** 2.0 * e * s.SecondMomentZ
+
+      mem.C1 * π2EIz / (l * l)
+      * sqrt (
+        s.WarpingConstant / s.SecondMomentZ
+        + l * l * g * s.TorsionConstant / π2EIz
+      )
+
+    /// <summary>
+    /// Non-dimensional slenderness and design buckling resistance, Nb,Rd,
+    /// about the given axis.
+    /// </summary>
+    let flexural
+      (s: ISection)
+      (m: SteelMaterial)
+      (curve: BucklingCurve)
+      (i: float<mm^4>)
+      (length: float<mm>)
+      : float * float<kN> =
+      let nRk = s.Area * m.YieldStrength
+      let λ = sqrt (nRk / criticalForce m i length)
+      let χ = reductionFactor (imperfection curve) λ
+      λ, (χ * nRk / γM1 |> toKilonewtons)
+
+    /// <summary>
+    /// Design buckling resistance moment, Mb,Rd (6.55).
+    /// </summary>
+    let lateralTorsional (c: SectionClass) (mem: SteelMember) : float<kNm> =
+      let s = mem.Section
+
+      let w =
+        match c with
+        | Class1
+        | Class2 -> s.PlasticModulusY
+        | Class3
+        | Class4 -> s.ElasticModulusY
+
+      let mRk = w * mem.Material.YieldStrength
+      let λ = sqrt (mRk / criticalMoment mem)
+      let α = imperfection (lateralTorsionalCurve s)
+      let χ = lateralTorsionalReductionFactor α λ
+      χ * mRk / γM1 |> toKilonewtonMetres
+
+  [<RequireQualifiedAccess>]
+  module Interaction =
+
+    /// <summary>
+    /// Interaction factors kyy, kyz, kzy and kzz to Annex B (Method 2)
+    /// for members susceptible to torsional deformation. Equivalent
+    /// uniform moment factors are conservatively taken as 1.0.
+    /// </summary>
+    let factors
+      (c: SectionClass)
+      (λy: float)
+      (λz: float)
+      (ny: float)
+      (nz: float)
+      : float * float * float * float =
+      let cm = 1.0
+      let cmLT = 1.0
+
+      match c with
+      | Class1
+      | Class2 ->
+        let kyy = cm * min (1.0 + (λy - 0.2) * ny) (1.0 + 0.8 * ny)
+        let kzz = cm * min (1.0 + (2.0 * λz - 0.6) * nz) (1.0 + 1.4 * nz)
+        let kyz = 0.6 * kzz
+        let upper = 1.0 - 0.1 * λz / (cmLT - 0.25) * nz
+        let lower = 1.0 - 0.1 / (cmLT - 0.25) * nz
+
+        let kzy =
+          match λz with
+          | λ when λ < 0.4 -> min (0.6 + λ) upper
+          | _ -> max upper lower
+
+        kyy, kyz, kzy, kzz
+      | Class3
+      | Class4 ->
+        let kyy = cm * min (1.0 + 0.6 * λy * ny) (1.0 + 0.6 * ny)
+        let kzz = cm * min (1.0 + 0.6 * λz * nz) (1.0 + 0.6 * nz)
+        let upper = 1.0 - 0.05 * λz / (cmLT - 0.25) * nz
+        let lower = 1.0 - 0.05 / (cmLT - 0.25) * nz
+        kyy, kzz, max upper lower, kzz
+
+  let private validate (mem: SteelMember) : Result<SteelMember, DesignError> =
+    let s = mem.Section
+    let l = mem.BucklingLengths
+
+    match s, l with
+    | s, _ when s.Area <= 0.0<mm^2> -> Error(InvalidInput "Area <= 0")
+    | s, _ when s.WebThickness <= 0.0<mm> ->
+      Error(InvalidInput "Web thickness <= 0")
+    | s, _ when s.FlangeThickness <= 0.0<mm> ->
+      Error(InvalidInput "Flange thickness <= 0")
+    | _, l when l.MajorAxis <= 0.0<mm> || l.MinorAxis <= 0.0<mm> ->
+      Error(InvalidInput "Buckling length <= 0")
+    | _, l when l.LateralTorsional <= 0.0<mm> ->
+      Error(InvalidInput "Lateral torsional buckling length <= 0")
+    | _ when mem.C1 <= 0.0 -> Error(InvalidInput "C1 <= 0")
+    | _ -> Ok mem
+
+  /// <summary>
+  /// Performs cross-section and member checks for a steel member
+  /// subject to axial force, major axis shear and biaxial bending.
+  /// Class 4 sections are outside the scope of this implementation.
+  /// </summary>
+  /// <param name="mem">Steel member.</param>
+  /// <param name="f">Design forces, compression positive.</param>
+  /// <returns>Design report or an error.</returns>
+  let check
+    (mem: SteelMember)
+    (f: MemberForces)
+    : Result<DesignReport, DesignError> =
+    match validate mem with
+    | Error e -> Error e
+    | Ok mem ->
+      let s = mem.Section
+      let m = mem.Material
+      let compression = f.Axial > 0.0<kN>
+      let c = Classification.section s m.YieldStrength f.Axial
+
+      match c with
+      | Class4 ->
+        Error(UnsupportedSection $"{s.Designation} is Class 4")
+      | _ ->
+        let nRd =
+          match compression with
+          | true -> Resistance.compression s m
+          | false -> Resistance.tension s m
+
+        let vRd = Resistance.shear s m
+        let myRd = Resistance.bendingYWithShear c s m f.ShearZ
+        let mzRd = Resistance.bendingZ c s m
+
+        let axial =
+          match compression with
+          | true -> DesignCheck.create "Compression" "6.2.4" f.Axial nRd
+          | false -> DesignCheck.create "Tension" "6.2.3" f.Axial nRd
+
+        let crossSection =
+          [ axial
+            DesignCheck.create "Shear" "6.2.6" f.ShearZ vRd
+            DesignCheck.create "Bending Y-Y" "6.2.5" f.MomentY myRd
+            DesignCheck.create "Bending Z-Z" "6.2.5" f.MomentZ mzRd
+            DesignCheck.ofUtilisation
+              "Combined Axial and Bending"
+              "6.2.1(7)"
+              (abs (f.Axial / nRd)
+               + abs (f.MomentY / myRd)
+               + abs (f.MomentZ / mzRd)) ]
+
+        let curveY, curveZ = Buckling.flexuralCurves s
+        let lengths = mem.BucklingLengths
+
+        let λy, nbyRd =
+          Buckling.flexural s m curveY s.SecondMomentY lengths.MajorAxis
+
+        let λz, nbzRd =
+          Buckling.flexural s m curveZ s.SecondMomentZ lengths.MinorAxis
+
+        let mbRd = Buckling.lateralTorsional c mem
+
+        let buckling =
+          [ if compression then
+              DesignCheck.create "Flexural Buckling Y-Y" "6.3.1" f.Axial nbyRd
+              DesignCheck.create "Flexural Buckling Z-Z" "6.3.1" f.Axial nbzRd
+            if f.MomentY <> 0.0<kNm> then
+              DesignCheck.create "LTB" "6.3.2" f.MomentY mbRd
+            if compression then
+              let ny = f.Axial / nbyRd
+              let nz = f.Axial / nbzRd
+              let kyy, kyz, kzy, kzz = Interaction.factors c λy λz ny nz
+              let my = abs (f.MomentY / mbRd)
+              let mz = abs (f.MomentZ / Resistance.bendingZ c s m)
+
+              DesignCheck.ofUtilisation
+                "Buckling Interaction Y-Y"
+                "6.3.3 (6.61)"
+                (ny + kyy * my + kyz * mz)
+
+              DesignCheck.ofUtilisation
+                "Buckling Interaction Z-Z"
+                "6.3.3 (6.62)"
+                (nz + kzy * my + kzz * mz) ]
+
+        (crossSection @ buckling)
+        |> DesignReport.create "EN 1993-1-1" mem.Name
+        |> Ok
+
+  /// <summary>
+  /// EN 1993-1-1 member design code plugin.
+  /// </summary>
+  let designCode: MemberDesignCode<SteelMember, MemberForces> =
+    { Name = "EN 1993-1-1"; Check = check }
@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Steel
+
+open System
+open System.Text.Json
+open Gazelle.Units
+open Gazelle.Geometry
+
+/// <summary>
+/// Families of standard UK steel profiles available in the catalogue.
+/// </summary>
+type SectionFamily =
+  | UB
+  | UC
+  | UBP
+  | PFC
+  | HotFinishedCHS
+  | HotFinishedRHS
+  | HotFinishedSHS
+  | HotFinishedEHS
+  | ColdFormedCHS
+  | ColdFormedRHS
+  | ColdFormedSHS
+
+type SectionError =
+  | UnknownFamily of string
+  | UnknownSection of string
+  | NotAnISection of string
+
+/// <summary>
+/// Catalogue of standard UK steel profiles, sourced from the SCI Blue
+/// Book. Designations take the form "UB 457x191x67" or "CF SHS 50x50x3.0".
+/// </summary>
+[<RequireQualifiedAccess>]
+module Sections =
+
+  let private families =
+    [ UB, "UB", "ub.json"
+      UC, "UC", "uc.json"
+      UBP, "UBP", "ubp.json"
+      PFC, "PFC", "pfc.json"
+      HotFinishedCHS, "CHS", "hf-chs.json"
+      HotFinishedRHS, "RHS", "hf-rhs.json"
+      HotFinishedSHS, "SHS", "hf-shs.json"
+      HotFinishedEHS, "EHS", "hf-ehs.json"
+      ColdFormedCHS, "CF CHS", "cf-chs.json"
+      ColdFormedRHS, "CF RHS", "cf-rhs.json"
+      ColdFormedSHS, "CF SHS", "cf-shs.json" ]
+
+  let private resource (family: SectionFamily) : string =
+    families
+    |> List.pick (fun (f, _, file) -> if f = family then Some file else None)
+
+  let private load (family: SectionFamily) : JsonElement =
+    let assembly = typeof<SectionError>.Assembly
+    let name = $"bluebook.{resource family}"
+    use stream = assembly.GetManifestResourceStream(name)
+    use document = JsonDocument.Parse(stream)
+    document.RootElement.Clone()
+
+  let private catalogue =
+    families
+    |> List.map (fun (family, _, _) -> family, lazy (load family))
+    |> Map.ofList
+
+  /// <summary>
+  /// Prefix used in designations for a family, e.g. "UB".
+  /// </summary>
+  /// <param name="family">Section family.</param>
+  /// <returns>Designation prefix.</returns>
+  let prefix (family: SectionFamily) : string =
+    families
+    |> List.pick (fun (f, p, _) -> if f = family then Some p else None)
+
+  /// <summary>
+  /// Splits a designation into its family and serial size.
+  /// </summary>
+  /// <param name="designation">Designation, e.g. "UB 457x191x67".</param>
+  /// <returns>Section family and serial size, or an error.</returns>
+  let tryParseDesignation
+    (designation: string)
+    : Result<SectionFamily * string, SectionError> =
+    let tokens =
+      designation.Trim().Split(' ', StringSplitOptions.RemoveEmptyEntries)
+
+    match tokens with
+    | [||] -> Error(UnknownSection designation)
+    | tokens ->
+      let size = Array.last tokens
+      let name = tokens[.. tokens.Length - 2] |> String.concat " "
+
+      let matches (_, p: string, _) =
+        String.Equals(p, name, StringComparison.OrdinalIgnoreCase)
+
+      match families |> List.tryFind matches with
+      | Some(family, _, _) -> Ok(family, size)
+      | None -> Error(UnknownFamily name)
+
+  let private tryFindElement (designation: string) =
+    designation
+    |> tryParseDesignation
+    |> Result.bind (fun (family, size) ->
+      match catalogue[family].Value.TryGetProperty(size) with
+      | true, element -> Ok(family, size, element)
+      | false, _ -> Error(UnknownSection designation))
+
+  let private read (element: JsonElement) (keys: string list) : float =
+    keys
+    |> List.pick (fun key ->
+      match element.TryGetProperty(key) with
+      | true, value -> Some(value.GetDouble())
+      | false, _ -> None)
+
+  let private cm2 (x: float) : float<mm^2> = x * 100.0<mm^2>
+  let private cm3 (x: float) : float<mm^3> = x * 1_000.0<mm^3>
+  let private cm4 (x: float) : float<mm^4> = x * 10_000.0<mm^4>
+  let private dm6 (x: float) : float<mm^6> = x * 1e12<mm^6>
+
+  let private secondMomentY =
+    [ "Second Moment of Area, Y-Y (cm4)"; "Second Moment of Area, I (cm4)" ]
+
+  let private secondMomentZ =
+    [ "Second Moment of Area, Z-Z (cm4)"; "Second Moment of Area, I (cm4)" ]
+
+  let private elasticModulusY =
+    [ "Elastic Modulus, Y-Y (cm3)"; "Elastic Modulus, Wel (cm3)" ]
+
+  let private elasticModulusZ =
+    [ "Elastic Modulus, Z-Z (cm3)"; "Elastic Modulus, Wel (cm3)" ]
+
+  let private plasticModulusY =
+    [ "Plastic Modulus, Y-Y (cm3)"; "Plastic Modulus, Wpl (cm3)" ]
+
+  let private plasticModulusZ =
+    [ "Plastic Modulus, Z-Z (cm3)"; "Plastic Modulus, Wpl (cm3)" ]
+
+  /// <summary>
+  /// Lists the serial sizes available for a section family.
+  /// </summary>
+  /// <param name="family">Section family.</param>
+  /// <returns>Designations in catalogue order.</returns>
+  let designations (family: SectionFamily) : string list =
+    [ for p in catalogue[family].Value.EnumerateObject() do
+        $"{prefix family} {p.Name}" ]
+
+  /// <summary>
+  /// Finds the geometric properties of a standard profile.
+  /// </summary>
+  /// <param name="designation">Designation, e.g. "UB 457x191x67".</param>
+  /// <returns>Section properties, or an error.</returns>
+  let tryFind (designation: string) : Result<SectionProperties, SectionError> =
+    designation
+    |> tryFindElement
+    |> Result.map (fun (family, size, e) ->
+      { Designation = $"{prefix family} {size}"
+        Area = read e [ "Area of Section, A (cm2)" ] |> cm2
+        SecondMomentY = read e secondMomentY |> cm4
+        SecondMomentZ = read e secondMomentZ |> cm4
+        TorsionConstant = read e [ "Torsional Constant, IT (cm4)" ] |> cm4
+        ElasticModulusY = read e elasticModulusY |> cm3
+        ElasticModulusZ = read e elasticModulusZ |> cm3
+        PlasticModulusY = read e plasticModulusY |> cm3
+        PlasticModulusZ = read e plasticModulusZ |> cm3 })
+
+  /// <summary>
+  /// Finds a rolled I or H profile (UB, UC or UBP) for member design.
+  /// </summary>
+  /// <param name="designation">Designation, e.g. "UC 254x254x73".</param>
+  /// <returns>I section, or an error.</returns>
+  let tryFindISection (designation: string) : Result<ISection, SectionError> =
+    designation
+    |> tryFindElement
+    |> Result.bind (fun (family, size, e) ->
+      match family with
+      | UB
+      | UC
+      | UBP ->
+        let mm (key: string) = read e [ key ] * 1.0<mm>
+
+        let section: ISection =
+          { Designation = $"{prefix family} {size}"
+            Depth = mm "Depth of Section, h (mm)"
+            Width = mm "Width of Section, b (mm)"
+            WebThickness = mm "Web Thickness, tw (mm)"
+            FlangeThickness = mm "Flange Thickness, tf (mm)"
+            RootRadius = mm "Root Radius, r (mm)"
+            Area = read e [ "Area of Section, A (cm2)" ] |> cm2
+            SecondMomentY = read e secondMomentY |> cm4
+            SecondMomentZ = read e secondMomentZ |> cm4
+            ElasticModulusY = read e elasticModulusY |> cm3
+            ElasticModulusZ = read e elasticModulusZ |> cm3
+            PlasticModulusY = read e plasticModulusY |> cm3
+            PlasticModulusZ = read e plasticModulusZ |> cm3
+            TorsionConstant = read e [ "Torsional Constant, IT (cm4)" ] |> cm4
+            WarpingConstant = read e [ "Warping Constant, Iw (dm6)" ] |> dm6 }
+
+        Ok section
+      | _ -> Error(NotAnISection designation))
+
+[<RequireQualifiedAccess>]
+module SectionError =
+
+  let getAsString (e: SectionError) : string =
+    match e with
+    | UnknownFamily msg -> $"Unknown Section Family: {msg}."
+    | UnknownSection msg -> $"Unknown Section: {msg}."
+    | NotAnISection msg -> $"Not an I Section: {msg}."
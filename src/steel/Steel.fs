@@ -0,0 +1,236 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Steel
+
+open Gazelle.Units
+
+/// <summary>
+/// Mechanical properties of structural steel.
+/// </summary>
+type SteelMaterial =
+  { YieldStrength: float<MPa>
+    UltimateStrength: float<MPa>
+    ElasticModulus: float<MPa>
+    ShearModulus: float<MPa> }
+
+/// <summary>
+/// Structural steel grades to EN 10025 and ASTM A36 / A992.
+/// </summary>
+type SteelGrade =
+  | S235
+  | S275
+  | S355
+  | S460
+  | A36
+  | A992
+
+type SteelGradeError =
+  | UnknownGrade of string
+  | ThicknessOutOfRange of string
+
+/// <summary>
+/// Doubly-symmetric rolled I or H section, e.g. UB or UC.
+/// Y-Y is the major axis and Z-Z is the minor axis.
+/// </summary>
+type ISection =
+  { Designation: string
+    Depth: float<mm>
+    Width: float<mm>
+    WebThickness: float<mm>
+    FlangeThickness: float<mm>
+    RootRadius: float<mm>
+    Area: float<mm^2>
+    SecondMomentY: float<mm^4>
+    SecondMomentZ: float<mm^4>
+    ElasticModulusY: float<mm^3>
+    ElasticModulusZ: float<mm^3>
+    PlasticModulusY: float<mm^3>
+    PlasticModulusZ: float<mm^3>
+    TorsionConstant: float<mm^4>
+    WarpingConstant: float<mm^6> }
+
+/// <summary>
+/// Effective lengths governing member buckling.
+/// </summary>
+type BucklingLengths =
+  { MajorAxis: float<mm>
+    MinorAxis: float<mm>
+    LateralTorsional: float<mm> }
+
+/// <summary>
+/// Steel member comprising a section, material and restraint conditions.
+/// C1 accounts for the shape of the bending moment diagram when
+/// calculating the elastic critical moment (1.0 for uniform moment).
+/// </summary>
+type SteelMember =
+  { Name: string
+    Section: ISection
+    Material: SteelMaterial
+    BucklingLengths: BucklingLengths
+    C1: float }
+
+/// <summary>
+/// Design forces acting on a member. Compression is positive.
+/// </summary>
+type MemberForces =
+  { Axial: float<kN>
+    ShearZ: float<kN>
+    MomentY: float<kNm>
+    MomentZ: float<kNm> }
+
+[<RequireQualifiedAccess>]
+module SteelGrade =
+
+  let private grades = [ S235; S275; S355; S460; A36; A992 ]
+
+  let private unlimited = System.Double.PositiveInfinity * 1.0<mm>
+
+  /// <summary>
+  /// Nominal thickness limits with their yield and ultimate strengths.
+  /// EN 10025 values are from Parts 2 (S235 to S355) and 3 (S460N).
+  /// </summary>
+  let private strengths (grade: SteelGrade) =
+    match grade with
+    | S235 ->
+      [ 16.0<mm>, 235.0<MPa>, 360.0<MPa>
+        40.0<mm>, 225.0<MPa>, 360.0<MPa>
+        100.0<mm>, 215.0<MPa>, 360.0<MPa>
+        150.0<mm>, 195.0<MPa>, 350.0<MPa> ]
+    | S275 ->
+      [ 16.0<mm>, 275.0<MPa>, 410.0<MPa>
+        40.0<mm>, 265.0<MPa>, 410.0<MPa>
+        63.0<mm>, 255.0<MPa>, 410.0<MPa>
+        80.0<mm>, 245.0<MPa>, 410.0<MPa>
+        100.0<mm>, 235.0<MPa>, 410.0<MPa>
+        150.0<mm>, 225.0<MPa>, 400.0<MPa> ]
+    | S355 ->
+      [ 16.0<mm>, 355.0<MPa>, 470.0<MPa>
+        40.0<mm>, 345.0<MPa>, 470.0<MPa>
+        63.0<mm>, 335.0<MPa>, 470.0<MPa>
+        80.0<mm>, 325.0<MPa>, 470.0<MPa>
+        100.0<mm>, 315.0<MPa>, 470.0<MPa>
+        150.0<mm>, 295.0<MPa>, 450.0<MPa> ]
+    | S460 ->
+      [ 16.0<mm>, 460.0<MPa>, 540.0<MPa>
+        40.0<mm>, 440.0<MPa>, 540.0<MPa>
+        63.0<mm>, 430.0<MPa>, 540.0<MPa>
+        80.0<mm>, 410.0<MPa>, 540.0<MPa>
+        100.0<mm>, 400.0<MPa>, 540.0<MPa>
+        150.0<mm>, 380.0<MPa>, 530.0<MPa> ]
+    | A36 ->
+      [ 203.2<mm>, 250.0<MPa>, 400.0<MPa>
+        unlimited, 220.0<MPa>, 400.0<MPa> ]
+    | A992 -> [ unlimited, 345.0<MPa>, 450.0<MPa> ]
+
+  /// <summary>
+  /// Name of a grade, e.g. "S355".
+  /// </summary>
+  /// <param name="grade">Steel grade.</param>
+  /// <returns>Grade name.</returns>
+  let name (grade: SteelGrade) : string =
+    match grade with
+    | S235 -> "S235"
+    | S275 -> "S275"
+    | S355 -> "S355"
+    | S460 -> "S460"
+    | A36 -> "A36"
+    | A992 -> "A992"
+
+  /// <summary>
+  /// Parses a grade from its name, ignoring case.
+  /// </summary>
+  /// <param name="s">Grade name, e.g. "S355".</param>
+  /// <returns>Steel grade, or an error.</returns>
+  let tryParse (s: string) : Result<SteelGrade, SteelGradeError> =
+    let matches grade =
+      System.String.Equals(
+        name grade,
+        s.Trim(),
+        System.StringComparison.OrdinalIgnoreCase
+      )
+
+    match grades |> List.tryFind matches with
+    | Some grade -> Ok grade
+    | None -> Error(UnknownGrade s)
+
+  /// <summary>
+  /// Elastic and shear moduli, E and G, of a grade: 210 GPa and 81 GPa
+  /// to EN 1993-1-1 3.2.6, or 200 GPa and 77.2 GPa to AISC 360 for the
+  /// ASTM grades.
+  /// </summary>
+  /// <param name="grade">Steel grade.</param>
+  /// <returns>Elastic and shear moduli.</returns>
+  let elasticModuli (grade: SteelGrade) : float<MPa> * float<MPa> =
+    match grade with
+    | S235
+    | S275
+    | S355
+    | S460 -> 210_000.0<MPa>, 81_000.0<MPa>
+    | A36
+    | A992 -> 200_000.0<MPa>, 77_200.0<MPa>
+
+  /// <summary>
+  /// Finds the yield and ultimate strengths of a grade for the nominal
+  /// thickness of the thickest element, e.g. the flange of a rolled I.
+  /// </summary>
+  /// <param name="grade">Steel grade.</param>
+  /// <param name="thickness">Nominal thickness.</param>
+  /// <returns>Yield and ultimate strengths, or an error.</returns>
+  let tryStrengths
+    (grade: SteelGrade)
+    (thickness: float<mm>)
+    : Result<float<MPa> * float<MPa>, SteelGradeError> =
+    let within (limit, _, _) = thickness <= limit
+
+    match strengths grade |> List.tryFind within with
+    | _ when thickness <= 0.0<mm> ->
+      Error(ThicknessOutOfRange "Thickness must be > 0")
+    | Some(_, fy, fu) -> Ok(fy, fu)
+    | None ->
+      Error(ThicknessOutOfRange $"{name grade} is limited to 150mm")
+
+[<RequireQualifiedAccess>]
+module SteelMaterial =
+
+  /// <summary>
+  /// Creates a steel material with the standard elastic properties
+  /// E = 210 GPa and G = 81 GPa.
+  /// </summary>
+  /// <param name="fy">Yield strength.</param>
+  /// <param name="fu">Ultimate tensile strength.</param>
+  /// <returns>Steel material.</returns>
+  let create (fy: float<MPa>) (fu: float<MPa>) : SteelMaterial =
+    { YieldStrength = fy
+      UltimateStrength = fu
+      ElasticModulus = 210_000.0<MPa>
+      ShearModulus = 81_000.0<MPa> }
+
+  /// <summary>
+  /// Creates a steel material from a grade, using the yield and
+  /// ultimate strengths for the nominal thickness and the elastic
+  /// moduli of the grade's design standard.
+  /// </summary>
+  /// <param name="grade">Steel grade.</param>
+  /// <param name="thickness">Nominal thickness.</param>
+  /// <returns>Steel material, or an error.</returns>
+  let ofGrade
+    (grade: SteelGrade)
+    (thickness: float<mm>)
+    : Result<SteelMaterial, SteelGradeError> =
+    let e, g = SteelGrade.elasticModuli grade
+
+    SteelGrade.tryStrengths grade thickness
+    |> Result.map (fun (fy, fu) ->
+      { create fy fu with
+          ElasticModulus = e
+          ShearModulus = g })
+
+[<RequireQualifiedAccess>]
+module SteelGradeError =
+
+  let getAsString (e: SteelGradeError) : string =
+    match e with
+    | UnknownGrade msg -> $"Unknown Steel Grade: {msg}."
+    | ThicknessOutOfRange msg -> $"Thickness Out of Range: {msg}."
@@ -39,3 +39,135 @@ module Convert =
   /// <param name="n">Value in Kilonewtons.</param>
   /// <returns>Value in Newtons.</returns>
   let kilonewtonsToNewtons (x: float<kN>) : float<N> = x * 1000.0<N / kN>
+
+  /// <summary>
+  /// Converts Degrees to Radians.
+  /// </summary>
+  /// <param name="x">Angle in Degrees.</param>
+  /// <returns>Angle in Radians.</returns>
+  let degreesToRadians (x: float<deg>) : float<rad> =
+    x * (System.Math.PI / 180.0) * 1.0<rad / deg>
+
+  /// <summary>
+  /// Converts Radians to Degrees.
+  /// </summary>
+  /// <param name="x">Angle in Radians.</param>
+  /// <returns>Angle in Degrees.</returns>
+  let radiansToDegrees (x: float<rad>) : float<deg> =
+    x * (180.0 / System.Math.PI) * 1.0<deg / rad>
+
+  /// <summary>
+  /// Converts Degrees Celsius to Kelvin.
+  /// </summary>
+  /// <param name="x">Temperature in Degrees Celsius.</param>
+  /// <returns>Temperature in Kelvin.</returns>
+  let celsiusToKelvin (x: float<degC>) : float<K> =
+    (x + 273.15<degC>) * 1.0<K / degC>
+
+  /// <summary>
+  /// Converts Kelvin to Degrees Celsius.
+  /// </summary>
+  /// <param name="x">Temperature in Kelvin.</param>
+  /// <returns>Temperature in Degrees Celsius.</returns>
+  let kelvinToCelsius (x: float<K>) : float<degC> =
+    x * 1.0<degC / K> - 273.15<degC>
+
+  /// <summary>
+  /// Converts Newton-Millimetres to Kilonewton-Metres.
+  /// </summary>
+  /// <param name="x">Value in Newton-Millimetres.</param>
+  /// <returns>Value in Kilonewton-Metres.</returns>
+  let newtonMillimetresToKilonewtonMetres (x: float<Nmm>) : float<kNm> =
+    x / 1_000_000.0<Nmm / kNm>
+
+  /// <summary>
+  /// Converts Kilonewton-Metres to Newton-Millimetres.
+  /// </summary>
+  /// <param name="x">Value in Kilonewton-Metres.</param>
+  /// <returns>Value in Newton-Millimetres.</returns>
+  let kilonewtonMetresToNewtonMillimetres (x: float<kNm>) : float<Nmm> =
+    x * 1_000_000.0<Nmm / kNm>
+
+  /// <summary>
+  /// Converts square millimetres to square metres.
+  /// </summary>
+  /// <param name="x">Area in square millimetres.</param>
+  /// <returns>Area in square metres.</returns>
+  let squareMillimetresToSquareMetres (x: float<mm^2>) : float<m^2> =
+    x / 1_000_000.0<mm^2 / m^2>
+
+  /// <summary>
+  /// Converts square metres to square millimetres.
+  /// </summary>
+  /// <param name="x">Area in square metres.</param>
+  /// <returns>Area in square millimetres.</returns>
+  let squareMetresToSquareMillimetres (x: float<m^2>) : float<mm^2> =
+    x * 1_000_000.0<mm^2 / m^2>
+
+  /// <summary>
+  /// Converts Megapascals to Kilopascals.
+  /// </summary>
+  /// <param name="x">Stress in Megapascals.</param>
+  /// <returns>Stress in Kilopascals.</returns>
+  let megapascalsToKilopascals (x: float<MPa>) : float<kPa> =
+    x * 1000.0<kPa / MPa>
+
+  /// <summary>
+  /// Converts Kilopascals to Megapascals.
+  /// </summary>
+  /// <param name="x">Stress in Kilopascals.</param>
+  /// <returns>Stress in Megapascals.</returns>
+  let kilopascalsToMegapascals (x: float<kPa>) : float<MPa> =
+    x / 1000.0<kPa / MPa>
+
+  /// <summary>
+  /// Converts Gigapascals to Megapascals.
+  /// </summary>
+  /// <param name="x">Stress in Gigapascals.</param>
+  /// <returns>Stress in Megapascals.</returns>
+  let gigapascalsToMegapascals (x: float<GPa>) : float<MPa> =
+    x * 1000.0<MPa / GPa>
+
+  /// <summary>
+  /// Converts Megapascals to Gigapascals.
+  /// </summary>
+  /// <param name="x">Stress in Megapascals.</param>
+  /// <returns>Stress in Gigapascals.</returns>
+  let megapascalsToGigapascals (x: float<MPa>) : float<GPa> =
+    x / 1000.0<MPa / GPa>
+
+  /// <summary>
+  /// Converts Pounds per Square Inch to Megapascals.
+  /// </summary>
+  /// <param name="x">Stress in Pounds per Square Inch.</param>
+  /// <returns>Stress in Megapascals.</returns>
+  let psiToMegapascals (x: float<psi>) : float<MPa> =
+    x * 0.006894757293168<MPa / psi>
+
+  /// <summary>
+  /// Converts Megapascals to Pounds per Square Inch.
+  /// </summary>
+  /// <param name="x">Stress in Megapascals.</param>
+  /// <returns>Stress in Pounds per Square Inch.</returns>
+  let megapascalsToPsi (x: float<MPa>) : float<psi> =
+    x / 0.006894757293168<MPa / psi>
+
+  /// <summary>
+  /// Converts Kilonewtons per Metre to Newtons per Millimetre.
+  /// </summary>
+  /// <param name="x">Line load in Kilonewtons per Metre.</param>
+  /// <returns>Line load in Newtons per Millimetre.</returns>
+  let kilonewtonsPerMetreToNewtonsPerMillimetre
+    (x: float<kNpm>)
+    : float<N / mm> =
+    x * 1.0<(N / mm) / kNpm>
+
+  /// <summary>
+  /// Converts Newtons per Millimetre to Kilonewtons per Metre.
+  /// </summary>
+  /// <param name="x">Line load in Newtons per Millimetre.</param>
+  /// <returns>Line load in Kilonewtons per Metre.</returns>
+  let newtonsPerMillimetreToKilonewtonsPerMetre
+    (x: float<N / mm>)
+    : float<kNpm> =
+    x / 1.0<(N / mm) / kNpm>
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Units
+
+/// <summary>
+/// Unit safe construction of derived quantities from their base dimensions.
+/// The resulting unit-of-measure is inferred from the inputs, such that
+/// Force / Area yields a Stress and Force * Length yields a Moment.
+/// </summary>
+[<RequireQualifiedAccess>]
+module Derive =
+
+  /// <summary>
+  /// Area enclosed by two orthogonal dimensions.
+  /// </summary>
+  /// <param name="width">Width of the region.</param>
+  /// <param name="depth">Depth of the region.</param>
+  /// <returns>Area annotated with unit-of-measure.</returns>
+  let area
+    (width: float<'TLength>)
+    (depth: float<'TLength>)
+    : float<'TLength^2> =
+    width * depth
+
+  /// <summary>
+  /// Moment generated by a force acting at a lever arm.
+  /// </summary>
+  /// <param name="force">Applied force.</param>
+  /// <param name="leverArm">Perpendicular distance to the pivot.</param>
+  /// <returns>Moment annotated with unit-of-measure.</returns>
+  let moment
+    (force: float<'TForce>)
+    (leverArm: float<'TLength>)
+    : float<'TForce * 'TLength> =
+    force * leverArm
+
+  /// <summary>
+  /// Mechanical stress generated by a force acting over an area.
+  /// </summary>
+  /// <param name="force">Applied force.</param>
+  /// <param name="area">Area resisting the force.</param>
+  /// <returns>Stress annotated with unit-of-measure.</returns>
+  let stress
+    (force: float<'TForce>)
+    (area: float<'TLength^2>)
+    : Stress<'TForce, 'TLength> =
+    Stress(force / area)
+
+  /// <summary>
+  /// Pressure generated by a force acting over an area.
+  /// </summary>
+  /// <param name="force">Applied force.</param>
+  /// <param name="area">Area resisting the force.</param>
+  /// <returns>Pressure annotated with unit-of-measure.</returns>
+  let pressure
+    (force: float<'TForce>)
+    (area: float<'TLength^2>)
+    : Pressure<'TForce, 'TLength> =
+    stress force area
+
+  /// <summary>
+  /// Density of a mass occupying a volume.
+  /// </summary>
+  /// <param name="mass">Mass of the body.</param>
+  /// <param name="volume">Volume occupied by the body.</param>
+  /// <returns>Density annotated with unit-of-measure.</returns>
+  let density
+    (mass: float<'TMass>)
+    (volume: float<'TLength^3>)
+    : Density<'TMass, 'TLength> =
+    Density(mass / volume)
+
+  /// <summary>
+  /// Resultant force of a stress acting over an area.
+  /// </summary>
+  /// <param name="stress">Applied stress.</param>
+  /// <param name="area">Area over which the stress acts.</param>
+  /// <returns>Force annotated with unit-of-measure.</returns>
+  let force
+    (stress: Stress<'TForce, 'TLength>)
+    (area: float<'TLength^2>)
+    : float<'TForce> =
+    let (Stress s) = stress
+    s * area
@@ -0,0 +1,510 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Units
+
+open System
+open System.Globalization
+open System.Text.RegularExpressions
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Physical dimension of a quantity.
+/// </summary>
+[<RequireQualifiedAccess>]
+type Dimension =
+  | Length
+  | Force
+  | Stress
+  | Moment
+  | LineLoad
+  | Acceleration
+  | Density
+  | Temperature
+  | Angle
+
+/// <summary>
+/// Unit-of-measure that can be carried alongside a value at runtime,
+/// e.g. when reading from the command line or a model file.
+/// </summary>
+[<RequireQualifiedAccess>]
+type UnitOfMeasure =
+  | Millimetre
+  | Metre
+  | Inch
+  | Foot
+  | Newton
+  | Kilonewton
+  | Kip
+  | Kilopascal
+  | Megapascal
+  | Psi
+  | Ksi
+  | NewtonMillimetre
+  | KilonewtonMetre
+  | KipFoot
+  | NewtonPerMillimetre
+  | KilonewtonPerMetre
+  | MetrePerSecondSquared
+  | KilogramPerCubicMetre
+  | Kelvin
+  | DegreeCelsius
+  | DegreeFahrenheit
+  | Radian
+  | Degree
+
+/// <summary>
+/// Value paired with its unit-of-measure, e.g. "4.5 kN".
+/// </summary>
+type Quantity = { Value: float; Unit: UnitOfMeasure }
+
+/// <summary>
+/// Errors raised when parsing or converting quantities.
+/// </summary>
+type UnitError =
+  | MalformedQuantity of string
+  | UnknownUnit of string
+  | IncompatibleUnits of UnitOfMeasure * UnitOfMeasure
+
+/// <summary>
+/// Functions to query runtime units-of-measure.
+/// </summary>
+[<RequireQualifiedAccess>]
+module UnitOfMeasure =
+
+  /// <summary>
+  /// Canonical symbol used when displaying the unit.
+  /// </summary>
+  /// <param name="u">Unit-of-measure.</param>
+  /// <returns>Unit symbol.</returns>
+  let symbol (u: UnitOfMeasure) : string =
+    match u with
+    | UnitOfMeasure.Millimetre -> "mm"
+    | UnitOfMeasure.Metre -> "m"
+    | UnitOfMeasure.Inch -> "in"
+    | UnitOfMeasure.Foot -> "ft"
+    | UnitOfMeasure.Newton -> "N"
+    | UnitOfMeasure.Kilonewton -> "kN"
+    | UnitOfMeasure.Kip -> "kip"
+    | UnitOfMeasure.Kilopascal -> "kPa"
+    | UnitOfMeasure.Megapascal -> "MPa"
+    | UnitOfMeasure.Psi -> "psi"
+    | UnitOfMeasure.Ksi -> "ksi"
+    | UnitOfMeasure.NewtonMillimetre -> "Nmm"
+    | UnitOfMeasure.KilonewtonMetre -> "kNm"
+    | UnitOfMeasure.KipFoot -> "kip-ft"
+    | UnitOfMeasure.NewtonPerMillimetre -> "N/mm"
+    | UnitOfMeasure.KilonewtonPerMetre -> "kN/m"
+    | UnitOfMeasure.MetrePerSecondSquared -> "m/s²"
+    | UnitOfMeasure.KilogramPerCubicMetre -> "kg/m³"
+    | UnitOfMeasure.Kelvin -> "K"
+    | UnitOfMeasure.DegreeCelsius -> "°C"
+    | UnitOfMeasure.DegreeFahrenheit -> "°F"
+    | UnitOfMeasure.Radian -> "rad"
+    | UnitOfMeasure.Degree -> "°"
+
+  /// <summary>
+  /// Physical dimension measured by the unit.
+  /// </summary>
+  /// <param name="u">Unit-of-measure.</param>
+  /// <returns>Dimension of the unit.</returns>
+  let dimension (u: UnitOfMeasure) : Dimension =
+    match u with
+    | UnitOfMeasure.Millimetre
+    | UnitOfMeasure.Metre
+    | UnitOfMeasure.Inch
+    | UnitOfMeasure.Foot -> Dimension.Length
+    | UnitOfMeasure.Newton
+    | UnitOfMeasure.Kilonewton
+    | UnitOfMeasure.Kip -> Dimension.Force
+    | UnitOfMeasure.Kilopascal
+    | UnitOfMeasure.Megapascal
+    | UnitOfMeasure.Psi
+    | UnitOfMeasure.Ksi -> Dimension.Stress
+    | UnitOfMeasure.NewtonMillimetre
+    | UnitOfMeasure.KilonewtonMetre
+    | UnitOfMeasure.KipFoot -> Dimension.Moment
+    | UnitOfMeasure.NewtonPerMillimetre
+    | UnitOfMeasure.KilonewtonPerMetre -> Dimension.LineLoad
+    | UnitOfMeasure.MetrePerSecondSquared -> Dimension.Acceleration
+    | UnitOfMeasure.KilogramPerCubicMetre -> Dimension.Density
+    | UnitOfMeasure.Kelvin
+    | UnitOfMeasure.DegreeCelsius
+    | UnitOfMeasure.DegreeFahrenheit -> Dimension.Temperature
+    | UnitOfMeasure.Radian
+    | UnitOfMeasure.Degree -> Dimension.Angle
+
+  /// <summary>
+  /// Multiplier converting the unit to its SI base, e.g. m, N, Pa or K.
+  /// </summary>
+  /// <param name="u">Unit-of-measure.</param>
+  /// <returns>Conversion factor to the SI base unit.</returns>
+  let toSIFactor (u: UnitOfMeasure) : float =
+    match u with
+    | UnitOfMeasure.Millimetre -> 0.001
+    | UnitOfMeasure.Metre -> 1.0
+    | UnitOfMeasure.Inch -> 0.0254
+    | UnitOfMeasure.Foot -> 0.3048
+    | UnitOfMeasure.Newton -> 1.0
+    | UnitOfMeasure.Kilonewton -> 1000.0
+    | UnitOfMeasure.Kip -> 4448.2216152605
+    | UnitOfMeasure.Kilopascal -> 1000.0
+    | UnitOfMeasure.Megapascal -> 1_000_000.0
+    | UnitOfMeasure.Psi -> 6894.757293168
+    | UnitOfMeasure.Ksi -> 6_894_757.293168
+    | UnitOfMeasure.NewtonMillimetre -> 0.001
+    | UnitOfMeasure.KilonewtonMetre -> 1000.0
+    | UnitOfMeasure.KipFoot -> 1355.8179483314
+    | UnitOfMeasure.NewtonPerMillimetre -> 1000.0
+    | UnitOfMeasure.KilonewtonPerMetre -> 1000.0
+    | UnitOfMeasure.MetrePerSecondSquared -> 1.0
+    | UnitOfMeasure.KilogramPerCubicMetre -> 1.0
+    | UnitOfMeasure.Kelvin -> 1.0
+    | UnitOfMeasure.DegreeCelsius -> 1.0
+    | UnitOfMeasure.DegreeFahrenheit -> 5.0 / 9.0
+    | UnitOfMeasure.Radian -> 1.0
+    | UnitOfMeasure.Degree -> System.Math.PI / 180.0
+
+  /// <summary>
+  /// Offset added after scaling to reach the SI base unit.
+  /// Only non-zero for temperature scales with a shifted origin.
+  /// </summary>
+  /// <param name="u">Unit-of-measure.</param>
+  /// <returns>Offset to the SI base unit.</returns>
+  let toSIOffset (u: UnitOfMeasure) : float =
+    match u with
+    | UnitOfMeasure.DegreeCelsius -> 273.15
+    | UnitOfMeasure.DegreeFahrenheit -> 459.67 * 5.0 / 9.0
+    | _ -> 0.0
+
+  /// <summary>
+  /// Attempts to match a unit symbol, including common alternative spellings.
+  /// </summary>
+  /// <param name="s">Unit symbol, e.g. "kN" or "N/mm2".</param>
+  /// <returns>Matching unit-of-measure or an error.</returns>
+  let tryParse (s: string) : Result<UnitOfMeasure, UnitError> =
+    match s.Trim() with
+    | "mm" -> Ok UnitOfMeasure.Millimetre
+    | "m" -> Ok UnitOfMeasure.Metre
+    | "in"
+    | "\"" -> Ok UnitOfMeasure.Inch
+    | "ft"
+    | "'" -> Ok UnitOfMeasure.Foot
+    | "N" -> Ok UnitOfMeasure.Newton
+    | "kN" -> Ok UnitOfMeasure.Kilonewton
+    | "kip"
+    | "kips" -> Ok UnitOfMeasure.Kip
+    | "kPa"
+    | "kN/m2"
+    | "kN/m²" -> Ok UnitOfMeasure.Kilopascal
+    | "MPa"
+    | "N/mm2"
+    | "N/mm²" -> Ok UnitOfMeasure.Megapascal
+    | "psi" -> Ok UnitOfMeasure.Psi
+    | "ksi" -> Ok UnitOfMeasure.Ksi
+    | "Nmm"
+    | "N.mm"
+    | "N·mm" -> Ok UnitOfMeasure.NewtonMillimetre
+    | "kNm"
+    | "kN.m"
+    | "kN·m" -> Ok UnitOfMeasure.KilonewtonMetre
+    | "kip-ft"
+    | "kip.ft" -> Ok UnitOfMeasure.KipFoot
+    | "N/mm" -> Ok UnitOfMeasure.NewtonPerMillimetre
+    | "kN/m" -> Ok UnitOfMeasure.KilonewtonPerMetre
+    | "m/s2"
+    | "m/s²" -> Ok UnitOfMeasure.MetrePerSecondSquared
+    | "kg/m3"
+    | "kg/m³" -> Ok UnitOfMeasure.KilogramPerCubicMetre
+    | "K" -> Ok UnitOfMeasure.Kelvin
+    | "°C"
+    | "degC" -> Ok UnitOfMeasure.DegreeCelsius
+    | "°F"
+    | "degF" -> Ok UnitOfMeasure.DegreeFahrenheit
+    | "rad" -> Ok UnitOfMeasure.Radian
+    | "°"
+    | "deg" -> Ok UnitOfMeasure.Degree
+    | other -> Error(UnknownUnit other)
+
+/// <summary>
+/// Functions to parse, display and convert quantities.
+/// </summary>
+[<RequireQualifiedAccess>]
+module Quantity =
+
+  let private pattern =
+    Regex(@"^\s*([-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?)\s*(\S.*?)\s*$")
+
+  /// <summary>
+  /// Creates a quantity from a value and unit.
+  /// </summary>
+  /// <param name="unit">Unit-of-measure.</param>
+  /// <param name="value">Numeric value.</param>
+  /// <returns>Quantity.</returns>
+  let create (unit: UnitOfMeasure) (value: float) : Quantity =
+    { Value = value; Unit = unit }
+
+  /// <summary>
+  /// Attempts to parse a quantity from text, e.g. "4.5 kN" or "35MPa".
+  /// </summary>
+  /// <param name="s">Number followed by a unit symbol.</param>
+  /// <returns>Parsed quantity or an error.</returns>
+  let tryParse (s: string) : Result<Quantity, UnitError> =
+    let m = pattern.Match(if isNull s then "" else s)
+
+    if not m.Success then
+      Error(MalformedQuantity s)
+    else
+      let number = m.Groups[1].Value
+      let symbol = m.Groups[2].Value
+      let style = NumberStyles.Float
+      let culture = CultureInfo.InvariantCulture
+
+      match Double.TryParse(number, style, culture) with
+      | false, _ -> Error(MalformedQuantity s)
+      | true, value ->
+        symbol
+        |> UnitOfMeasure.tryParse
+        |> Result.map (fun u -> create u value)
+
+  /// <summary>
+  /// Formats a quantity as text, e.g. "4.5 kN", independent of culture.
+  /// </summary>
+  /// <param name="q">Quantity.</param>
+  /// <returns>Text representation of the quantity.</returns>
+  let toString (q: Quantity) : string =
+    let value = q.Value.ToString("R", CultureInfo.InvariantCulture)
+    $"{value} {UnitOfMeasure.symbol q.Unit}"
+
+  /// <summary>
+  /// Attempts to convert a quantity to another unit of the same dimension.
+  /// </summary>
+  /// <param name="target">Desired unit-of-measure.</param>
+  /// <param name="q">Quantity to convert.</param>
+  /// <returns>Converted quantity or an error.</returns>
+  let tryConvert
+    (target: UnitOfMeasure)
+    (q: Quantity)
+    : Result<Quantity, UnitError> =
+    match UnitOfMeasure.dimension q.Unit, UnitOfMeasure.dimension target with
+    | d1, d2 when d1 <> d2 -> Error(IncompatibleUnits(q.Unit, target))
+    | _ ->
+      let si =
+        q.Value * UnitOfMeasure.toSIFactor q.Unit
+        + UnitOfMeasure.toSIOffset q.Unit
+
+      let value =
+        (si - UnitOfMeasure.toSIOffset target)
+        / UnitOfMeasure.toSIFactor target
+
+      Ok(create target value)
+
+  /// <summary>
+  /// Attempts to parse a quantity and convert it to the given unit.
+  /// </summary>
+  /// <param name="target">Desired unit-of-measure.</param>
+  /// <param name="s">Number followed by a unit symbol.</param>
+  /// <returns>Value expressed in the target unit or an error.</returns>
+  let tryParseAs
+    (target: UnitOfMeasure)
+    (s: string)
+    : Result<float, UnitError> =
+    s
+    |> tryParse
+    |> Result.bind (tryConvert target)
+    |> Result.map (fun q -> q.Value)
+
+  /// <summary>
+  /// Bridges between runtime quantities and unit-of-measure annotated floats.
+  /// </summary>
+  [<RequireQualifiedAccess>]
+  module Typed =
+
+    let private toTyped
+      (target: UnitOfMeasure)
+      (q: Quantity)
+      : Result<float<'T>, UnitError> =
+      q
+      |> tryConvert target
+      |> Result.map (fun q -> AddUnits.toFloat q.Value)
+
+    /// <summary>
+    /// Creates a quantity from a value in millimetres.
+    /// </summary>
+    let ofMillimetres (x: float<mm>) =
+      create UnitOfMeasure.Millimetre (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in metres.
+    /// </summary>
+    let ofMetres (x: float<m>) = create UnitOfMeasure.Metre (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in newtons.
+    /// </summary>
+    let ofNewtons (x: float<N>) = create UnitOfMeasure.Newton (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in kilonewtons.
+    /// </summary>
+    let ofKilonewtons (x: float<kN>) =
+      create UnitOfMeasure.Kilonewton (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in megapascals.
+    /// </summary>
+    let ofMegapascals (x: float<MPa>) =
+      create UnitOfMeasure.Megapascal (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in kilonewton metres.
+    /// </summary>
+    let ofKilonewtonMetres (x: float<kNm>) =
+      create UnitOfMeasure.KilonewtonMetre (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in kilonewtons per metre.
+    /// </summary>
+    let ofKilonewtonsPerMetre (x: float<kNpm>) =
+      create UnitOfMeasure.KilonewtonPerMetre (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in kilopascals.
+    /// </summary>
+    let ofKilopascals (x: float<kPa>) =
+      create UnitOfMeasure.Kilopascal (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in metres per second squared.
+    /// </summary>
+    let ofAcceleration (x: float<m / s^2>) =
+      create UnitOfMeasure.MetrePerSecondSquared (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in kilograms per cubic metre.
+    /// </summary>
+    let ofDensity (x: float<kg / m^3>) =
+      create UnitOfMeasure.KilogramPerCubicMetre (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in degrees Celsius.
+    /// </summary>
+    let ofCelsius (x: float<degC>) =
+      create UnitOfMeasure.DegreeCelsius (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in radians.
+    /// </summary>
+    let ofRadians (x: float<rad>) = create UnitOfMeasure.Radian (float x)
+
+    /// <summary>
+    /// Creates a quantity from a value in degrees.
+    /// </summary>
+    let ofDegrees (x: float<deg>) = create UnitOfMeasure.Degree (float x)
+
+    /// <summary>
+    /// Converts a quantity to a value in millimetres, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toMillimetres (q: Quantity) : Result<float<mm>, UnitError> =
+      toTyped UnitOfMeasure.Millimetre q
+
+    /// <summary>
+    /// Converts a quantity to a value in metres, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toMetres (q: Quantity) : Result<float<m>, UnitError> =
+      toTyped UnitOfMeasure.Metre q
+
+    /// <summary>
+    /// Converts a quantity to a value in newtons, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toNewtons (q: Quantity) : Result<float<N>, UnitError> =
+      toTyped UnitOfMeasure.Newton q
+
+    /// <summary>
+    /// Converts a quantity to a value in kilonewtons, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toKilonewtons (q: Quantity) : Result<float<kN>, UnitError> =
+      toTyped UnitOfMeasure.Kilonewton q
+
+    /// <summary>
+    /// Converts a quantity to a value in megapascals, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toMegapascals (q: Quantity) : Result<float<MPa>, UnitError> =
+      toTyped UnitOfMeasure.Megapascal q
+
+    /// <summary>
+    /// Converts a quantity to a value in kilonewton metres, failing if the
+    /// dimension differs.
+    /// </summary>
+    let toKilonewtonMetres (q: Quantity) : Result<float<kNm>, UnitError> =
+      toTyped UnitOfMeasure.KilonewtonMetre q
+
+    /// <summary>
+    /// Converts a quantity to a value in kilonewtons per metre, failing if the
+    /// dimension differs.
+    /// </summary>
+    let toKilonewtonsPerMetre (q: Quantity) : Result<float<kNpm>, UnitError> =
+      toTyped UnitOfMeasure.KilonewtonPerMetre q
+
+    /// <summary>
+    /// Converts a quantity to a value in kilopascals, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toKilopascals (q: Quantity) : Result<float<kPa>, UnitError> =
+      toTyped UnitOfMeasure.Kilopascal q
+
+    /// <summary>
+    /// Converts a quantity to a value in metres per second squared, failing if
+    /// the dimension differs.
+    /// </summary>
+    let toAcceleration (q: Quantity) : Result<float<m / s^2>, UnitError> =
+      toTyped UnitOfMeasure.MetrePerSecondSquared q
+
+    /// <summary>
+    /// Converts a quantity to a value in kilograms per cubic metre, failing if
+    /// the dimension differs.
+    /// </summary>
+    let toDensity (q: Quantity) : Result<float<kg / m^3>, UnitError> =
+      toTyped UnitOfMeasure.KilogramPerCubicMetre q
+
+    /// <summary>
+    /// Converts a quantity to a value in degrees Celsius, failing if the
+    /// dimension differs.
+    /// </summary>
+    let toCelsius (q: Quantity) : Result<float<degC>, UnitError> =
+      toTyped UnitOfMeasure.DegreeCelsius q
+
+    /// <summary>
+    /// Converts a quantity to a value in radians, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toRadians (q: Quantity) : Result<float<rad>, UnitError> =
+      toTyped UnitOfMeasure.Radian q
+
+    /// <summary>
+    /// Converts a quantity to a value in degrees, failing if the dimension
+    /// differs.
+    /// </summary>
+    let toDegrees (q: Quantity) : Result<float<deg>, UnitError> =
+      toTyped UnitOfMeasure.Degree q
+
+/// <summary>
+/// Error handling helpers for <see cref="UnitError"/>.
+/// </summary>
+[<RequireQualifiedAccess>]
+module UnitError =
+
+  let getAsString (e: UnitError) : string =
+    match e with
+    | MalformedQuantity s -> $"Malformed quantity '{s}'."
+    | UnknownUnit s -> $"Unknown unit '{s}'."
+    | IncompatibleUnits(u1, u2) ->
+      let s1 = UnitOfMeasure.symbol u1
+      let s2 = UnitOfMeasure.symbol u2
+      $"Cannot convert '{s1}' to '{s2}'."
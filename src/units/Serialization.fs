@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Units
+
+open System
+open System.Text.Json
+open System.Text.Json.Serialization
+
+/// <summary>
+/// Serializes a <see cref="Quantity"/> as { "value": 35.0, "unit": "MPa" }.
+/// Units are validated on read and, when a target unit is supplied,
+/// values are converted so that consumers receive consistent units.
+/// </summary>
+/// <param name="target">Optional unit to convert into on read.</param>
+type QuantityJsonConverter(target: UnitOfMeasure option) =
+  inherit JsonConverter<Quantity>()
+
+  new() = QuantityJsonConverter(None)
+
+  override _.Read
+    (reader: byref<Utf8JsonReader>, _: Type, _: JsonSerializerOptions)
+    : Quantity =
+    if reader.TokenType <> JsonTokenType.StartObject then
+      raise (JsonException "Expected a quantity object.")
+
+    let mutable value = None
+    let mutable symbol = None
+    let mutable finished = false
+
+    while not finished && reader.Read() do
+      match reader.TokenType with
+      | JsonTokenType.EndObject -> finished <- true
+      | JsonTokenType.PropertyName ->
+        let name = reader.GetString()
+        reader.Read() |> ignore
+
+        match name.ToLowerInvariant(), reader.TokenType with
+        | "value", JsonTokenType.Number -> value <- Some(reader.GetDouble())
+        | "value", _ -> raise (JsonException "Quantity value must be a number.")
+        | "unit", JsonTokenType.String -> symbol <- Some(reader.GetString())
+        | "unit", _ -> raise (JsonException "Quantity unit must be a string.")
+        | other, _ -> raise (JsonException $"Unknown quantity field '{other}'.")
+      | _ -> raise (JsonException "Malformed quantity object.")
+
+    match value, symbol with
+    | Some v, Some s ->
+      let quantity =
+        s
+        |> UnitOfMeasure.tryParse
+        |> Result.map (fun u -> Quantity.create u v)
+
+      let converted =
+        match target with
+        | Some t -> quantity |> Result.bind (Quantity.tryConvert t)
+        | None -> quantity
+
+      match converted with
+      | Ok q -> q
+      | Error e -> raise (JsonException(UnitError.getAsString e))
+    | _ -> raise (JsonException "Quantity requires a 'value' and a 'unit'.")
+
+  override _.Write
+    (writer: Utf8JsonWriter, q: Quantity, _: JsonSerializerOptions)
+    : unit =
+    writer.WriteStartObject()
+    writer.WriteNumber("value", q.Value)
+    writer.WriteString("unit", UnitOfMeasure.symbol q.Unit)
+    writer.WriteEndObject()
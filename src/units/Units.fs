@@ -30,6 +30,60 @@ type kN
 [<Measure>]
 type kNm = kN * m
 
+/// <summary>
+/// Moment or Torque in Newton-Millimetres.
+/// </summary>
+[<Measure>]
+type Nmm = N * mm
+
+/// <summary>
+/// Stress or Pressure in Kilopascals.
+/// </summary>
+[<Measure>]
+type kPa = kN / m^2
+
+/// <summary>
+/// Stress or Pressure in Megapascals.
+/// </summary>
+[<Measure>]
+type MPa = N / mm^2
+
+/// <summary>
+/// Stress or Pressure in Gigapascals.
+/// </summary>
+[<Measure>]
+type GPa = kN / mm^2
+
+/// <summary>
+/// Stress or Pressure in Pounds per Square Inch.
+/// </summary>
+[<Measure>]
+type psi
+
+/// <summary>
+/// Line load in Kilonewtons per Metre.
+/// </summary>
+[<Measure>]
+type kNpm = kN / m
+
+/// <summary>
+/// Plane angle in Radians.
+/// </summary>
+[<Measure>]
+type rad
+
+/// <summary>
+/// Plane angle in Degrees.
+/// </summary>
+[<Measure>]
+type deg
+
+/// <summary>
+/// Temperature in Degrees Celsius.
+/// </summary>
+[<Measure>]
+type degC
+
 /// <summary>
 /// Number of layers.
 /// </summary>
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+// Gazelle: a fast, cross-platform engine for structural analysis & design.
+// Copyright (C) 2024 James S. Bayley
+
+namespace Gazelle.Vibration
+
+open Gazelle.Design
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+/// <summary>
+/// Floor mode with its unity-normalised mode shape amplitudes at the
+/// excitation and response points.
+/// </summary>
+type FloorMode =
+  { Frequency: float<Hz>
+    ModalMass: float<kg>
+    Damping: float
+    Excitation: float
+    Response: float }
+
+/// <summary>
+/// Weighted response of a floor to walking at a given pace frequency.
+/// </summary>
+type FootfallResponse =
+  { WalkingFrequency: float<Hz>
+    Acceleration: float<m / s^2>
+    ResponseFactor: float }
+
+/// <summary>
+/// Named group of response points on a floor, e.g. an office bay, with
+/// the floor modes evaluated at each point.
+/// </summary>
+type FloorGroup =
+  { Name: string
+    Points: FloorMode list list }
+
+/// <summary>
+/// Critical response of a group of floor points.
+/// </summary>
+type FootfallReport =
+  { Group: string
+    Point: int
+    Response: FootfallResponse }
+
+/// <summary>
+/// Resonant response of low-frequency floors (below 10Hz) to walking, to
+/// SCI P354 Section 3. Steady-state responses of each mode are summed
+/// with their phase at each harmonic, frequency weighted and combined as
+/// an RMS acceleration.
+/// </summary>
+[<RequireQualifiedAccess>]
+module Footfall =
+
+  let private weight = 746.0<N>
+  let private baseAcceleration = 0.005<m / s^2>
+  let private harmonics = [ 1..4 ]
+
+  /// <summary>
+  /// Fourier coefficient of the h-th walking harmonic, αh (Table 3.1).
+  /// </summary>
+  /// <param name="h">Harmonic number, 1 to 4.</param>
+  /// <param name="fp">Pace frequency.</param>
+  /// <returns>Fourier coefficient.</returns>
+  let fourierCoefficient (h: int) (fp: float<Hz>) : float =
+    let hfp = float h * float fp
+
+    match h with
+    | 1 -> 0.436 * (hfp - 0.95)
+    | 2 -> 0.006 * (hfp + 12.3)
+    | 3 -> 0.007 * (hfp + 5.2)
+    | 4 -> 0.007 * (hfp + 2.0)
+    | _ -> 0.0
+
+  /// <summary>
+  /// Walking speed for a pace frequency between 1.7Hz and 2.4Hz.
+  /// </summary>
+  let walkingSpeed (fp: float<Hz>) : float<m / s> =
+    let fp = float fp
+    (1.67 * fp * fp - 4.83 * fp + 4.50) * 1.0<m / s>
+
+  /// <summary>
+  /// Frequency weighting for whole-body vertical vibration, Wb, using
+  /// the asymptotic approximation of BS 6841.
+  /// </summary>
+  let weighting (f: float<Hz>) : float =
+    match float f with
+    | f when f < 2.0 -> 0.4
+    | f when f < 5.0 -> f / 5.0
+    | f when f <= 16.0 -> 1.0
+    | f -> 16.0 / f
+
+  /// <summary>
+  /// Weighted RMS acceleration and response factor for walking at a pace
+  /// frequency along a path of the given length.
+  /// </summary>
+  /// <param name="path">Length of the walking path.</param>
+  /// <param name="fp">Pace frequency.</param>
+  /// <param name="modes">Floor modes.</param>
+  /// <returns>Floor response.</returns>
+  let resonantResponse
+    (path: float<m>)
+    (fp: float<Hz>)
+    (modes: FloorMode list)
+    : FootfallResponse =
+    // Real and imaginary parts of a mode's steady-state response, so
+    // modes are combined with their phase (P354 Section 3.4).
+    let acceleration h (mode: FloorMode) =
+      let f = float h * fp
+      let β = f / mode.Frequency
+      let ζ = mode.Damping
+      let denominator = (1.0 - β * β) ** 2.0 + (2.0 * ζ * β) ** 2.0
+      let cycles = path * fp / walkingSpeed fp
+      let ρ = 1.0 - exp (-2.0 * System.Math.PI * ζ * cycles)
+      let force = fourierCoefficient h fp * weight
+      let μ = mode.Excitation * mode.Response
+      let a = weighting f * μ * force / mode.ModalMass * ρ / denominator
+      a * (1.0 - β * β) * β * β, a * 2.0 * ζ * β ** 3.0
+
+    let peak =
+      harmonics
+      |> List.sumBy (fun h ->
+        let parts = modes |> List.map (acceleration h)
+        let re = List.sumBy fst parts
+        let im = List.sumBy snd parts
+        re * re + im * im)
+      |> sqrt
+
+    let rms = peak / sqrt 2.0
+
+    { WalkingFrequency = fp
+      Acceleration = rms
+      ResponseFactor = rms / baseAcceleration }
+
+  /// <summary>
+  /// Critical response over pace frequencies from 1.8Hz to 2.2Hz.
+  /// </summary>
+  /// <param name="path">Length of the walking path.</param>
+  /// <param name="modes">Floor modes below 15Hz.</param>
+  /// <returns>Response at the critical pace frequency, or an error.</returns>
+  let responseFactor
+    (path: float<m>)
+    (modes: FloorMode list)
+    : Result<FootfallResponse, DesignError> =
+    let invalid (m: FloorMode) =
+      m.Frequency <= 0.0<Hz>
+      || m.ModalMass <= 0.0<kg>
+      || m.Damping <= 0.0
+      || m.Damping >= 1.0
+
+    match path, modes with
+    | _, [] -> Error(InvalidInput "Floor has no modes")
+    | path, _ when path <= 0.0<m> ->
+      Error(InvalidInput "Walking path length must be > 0")
+    | _, modes when modes |> List.exists invalid ->
+      Error(InvalidInput "Modes need a frequency, modal mass and damping > 0")
+    | path, modes ->
+      [ 180..220 ]
+      |> List.map (fun f -> float f / 100.0 * 1.0<Hz>)
+      |> List.map (fun fp -> resonantResponse path fp modes)
+      |> List.maxBy (fun r -> r.ResponseFactor)
+      |> Ok
+
+  /// <summary>
+  /// Critical response factor of each group of floor points. Points are
+  /// numbered from 1 in the order given.
+  /// </summary>
+  /// <param name="path">Length of the walking path.</param>
+  /// <param name="groups">Groups of floor points.</param>
+  /// <returns>Critical response of each group, or an error.</returns>
+  let report
+    (path: float<m>)
+    (groups: FloorGroup list)
+    : Result<FootfallReport list, DesignError> =
+    let critical (g: FloorGroup) =
+      match g.Points with
+      | [] -> Error(InvalidInput $"Group {g.Name} has no points")
+      | points ->
+        points
+        |> List.mapi (fun i modes ->
+          responseFactor path modes |> Result.map (fun r -> i + 1, r))
+        |> List.fold
+          (fun acc r ->
+            match acc, r with
+            | Ok acc, Ok r -> Ok(acc @ [ r ])
+            | Error e, _
+            | _, Error e -> Error e)
+          (Ok [])
+        |> Result.map (fun responses ->
+          let point, response =
+            responses |> List.maxBy (fun (_, r) -> r.ResponseFactor)
+
+          { Group = g.Name
+            Point = point
+            Response = response })
+
+    groups
+    |> List.fold
+      (fun acc g ->
+        match acc, critical g with
+        | Ok acc, Ok r -> Ok(acc @ [ r ])
+        | Error e, _
+        | _, Error e -> Error e)
+      (Ok [])
@@ -0,0 +1,277 @@
+namespace Gazelle.CLI.Tests
+
+open System.Text.Json
+open System.Text.Json.Nodes
+open Xunit
+open Gazelle.CLI
+open Gazelle.CLI.Arguments
+open Gazelle.CLI.Batch
+open Gazelle.CLI.Diff
+open Gazelle.CLI.Validation
+
+module ArgumentsTests =
+
+  [<Fact>]
+  let ``Diff takes both files after options`` () =
+    let actual = parse [| "diff"; "--results"; "a.json"; "b.json" |]
+    Assert.Equal("diff", actual.Command)
+    Assert.True(actual.Results)
+    Assert.Equal(Some "a.json", actual.InputFile)
+    Assert.Equal(Some "b.json", actual.CompareFile)
+
+  [<Fact>]
+  let ``Diff takes files between and after options`` () =
+    let actual =
+      parse
+        [| "diff"
+           "--tolerance"
+           "0.01"
+           "a.json"
+           "--format"
+           "json"
+           "b.json" |]
+
+    Assert.Equal(0.01, actual.Tolerance)
+    Assert.Equal("json", actual.Format)
+    Assert.Equal(Some "a.json", actual.InputFile)
+    Assert.Equal(Some "b.json", actual.CompareFile)
+
+  [<Fact>]
+  let ``Diff takes files before options`` () =
+    let actual = parse [| "diff"; "a.json"; "b.json"; "--results" |]
+    Assert.True(actual.Results)
+    Assert.Equal(Some "a.json", actual.InputFile)
+    Assert.Equal(Some "b.json", actual.CompareFile)
+
+  [<Fact>]
+  let ``Model file may follow options`` () =
+    let actual = parse [| "analyze"; "--verbose"; "model.json" |]
+    Assert.True(actual.Verbose)
+    Assert.Equal(Some "model.json", actual.InputFile)
+    Assert.Equal(None, actual.CompareFile)
+
+module BatchTests =
+
+  open System.IO
+
+  [<Fact>]
+  let ``Batch search skips the results of earlier runs`` () =
+    let directory = Directory.CreateTempSubdirectory("gazelle-").FullName
+
+    try
+      for name in [ "b.json"; "a.json"; "a.results.json"; "summary.csv" ] do
+        File.WriteAllText(Path.Combine(directory, name), "{}")
+
+      let expected = [| "a.json"; "b.json" |]
+
+      for pattern in [ directory; Path.Combine(directory, "*.json") ] do
+        let actual =
+          findModels pattern |> Array.map (fun f -> Path.GetFileName(f: string))
+
+        Assert.Equal<string[]>(expected, actual)
+    finally
+      Directory.Delete(directory, true)
+
+module DiffTests =
+
+  let private json (s: string) = JsonDocument.Parse(s).RootElement
+
+  let private summary (entries: DiffEntry list) =
+    entries |> List.map (fun e -> e.Change, e.Section, e.Id)
+
+  [<Fact>]
+  let ``Model diff reports added, removed and modified entities`` () =
+    let a =
+      json
+        """{ "nodes": { "n1": { "x": 0 }, "n2": { "x": 1 } },
+             "elements": { "e1": { "nodes": ["n1", "n2"] } } }"""
+
+    let b =
+      json
+        """{ "nodes": { "n1": { "x": 0 }, "n2": { "x": 2 }, "n3": { "x": 3 } },
+             "groups": { "roof": { "id": "roof" } } }"""
+
+    let expected =
+      [ "Modified", "nodes", "n2"
+        "Added", "nodes", "n3"
+        "Removed", "elements", "e1"
+        "Added", "groups", "roof" ]
+
+    let actual = diffModels a b
+    Assert.Equal<(string * string * string) list>(expected, summary actual)
+
+  [<Fact>]
+  let ``Identical models have no differences`` () =
+    let a = json """{ "nodes": { "n1": { "x": 0 } } }"""
+    Assert.Empty(diffModels a a)
+
+  [<Fact>]
+  let ``Result diff ignores changes within tolerance`` () =
+    let a = json """{ "u": [0.010, 0.020], "r": { "n1": 5.0 } }"""
+    let b = json """{ "u": [0.0101, 0.030], "m": 1.0 }"""
+
+    let expected =
+      [ "Added", "results", "m"
+        "Removed", "results", "r.n1"
+        "Changed", "results", "u[1]" ]
+
+    let actual = diffResults 0.001 a b
+    Assert.Equal<(string * string * string) list>(expected, summary actual)
+
+module SchemaTests =
+
+  let private node (s: string) = JsonNode.Parse(s)
+
+  [<Fact>]
+  let ``Models without a schema version are migrated to the current one`` () =
+    match Schema.migrate (node """{ "info": { "version": "1.0" } }""") with
+    | Ok model -> Assert.Equal(Ok Schema.currentVersion, Schema.version model)
+    | Error e -> failwith e
+
+  [<Fact>]
+  let ``Models from a newer schema are rejected`` () =
+    let model = node """{ "schema_version": 99 }"""
+    Assert.True(Result.isError (Schema.migrate model))
+
+  [<Fact>]
+  let ``Unknown top-level sections are reported`` () =
+    let model =
+      JsonDocument.Parse("""{ "info": { "version": "1.0" }, "Name": "x" }""")
+
+    let expected = [ "Unknown model section: Name" ]
+    Assert.Equal<string list>(expected, Schema.check model.RootElement)
+
+  [<Fact>]
+  let ``Template models pass validation`` () =
+    for template in [ "beam"; "truss"; "frame" ] do
+      let model = (Templates.model template).Value
+      let result = diagnoseModel (JsonSerializer.SerializeToElement(model))
+      Assert.Empty(result.Errors)
+      Assert.Empty(result.Warnings)
+
+module ValidationTests =
+
+  let private diagnose (s: string) =
+    diagnoseModel (JsonDocument.Parse(s).RootElement)
+
+  [<Fact>]
+  let ``Elements referencing missing nodes are errors`` () =
+    let result =
+      diagnose
+        """{ "nodes": { "n1": {} },
+             "elements": { "e1": { "nodes": ["n1", "n9"] } } }"""
+
+    Assert.False(result.IsValid)
+    Assert.Contains("Element e1 references missing node n9", result.Errors)
+
+  [<Fact>]
+  let ``Nodes without elements are reported as free`` () =
+    let result =
+      diagnose
+        """{ "nodes": { "n1": {}, "n2": {}, "n3": {} },
+             "elements": { "e1": { "nodes": ["n1", "n2"] } } }"""
+
+    let expected = [| "Node n3 is not connected to any element" |]
+    Assert.Equal<string[]>(expected, result.Warnings)
+
+  [<Fact>]
+  let ``Disconnected parts of a model are reported as islands`` () =
+    let result =
+      diagnose
+        """{ "nodes": { "n1": {}, "n2": {}, "n3": {}, "n4": {}, "n5": {} },
+             "elements": { "e1": { "nodes": ["n1", "n2"] },
+                           "e2": { "nodes": ["n2", "n3"] },
+                           "e3": { "nodes": ["n4", "n5"] } } }"""
+
+    let expected =
+      [| "Nodes n4, n5 are disconnected from the rest of the model" |]
+
+    Assert.Equal<string[]>(expected, result.Warnings)
+
+  [<Fact>]
+  let ``Node references that are not strings are errors`` () =
+    let result =
+      diagnose
+        """{ "nodes": { "n1": {}, "n2": {} },
+             "elements": { "e1": { "nodes": ["n1", "n2"] },
+                           "e2": { "nodes": ["n1", 2] } } }"""
+
+    let expected = "Element e2 has a node reference that is not a string: 2"
+    Assert.False(result.IsValid)
+    Assert.Contains(expected, result.Errors)
+
+  let private beam constraints =
+    diagnose
+      $"""{{ "info": {{ "version": "1.0" }},
+             "nodes": {{ "n1": {{ "x": 0 }}, "n2": {{ "x": 3 }} }},
+             "elements": {{
+               "e1": {{ "type": "Beam", "nodes": ["n1", "n2"] }} }},
+             "constraints": {{ {constraints} }} }}"""
+
+  [<Fact>]
+  let ``Unsupported part of a model is a mechanism`` () =
+    let result = beam ""
+    Assert.False(result.IsValid)
+
+    let expected =
+      [| "Nodes n1, n2 form a mechanism free to move in "
+         + "n1 Ux, n1 Uz, n1 Ry, n2 Ux, n2 Uz, n2 Ry" |]
+
+    Assert.Equal<string[]>(expected, result.Errors)
+
+  [<Fact>]
+  let ``Constraint without restrained DOFs does not support a part`` () =
+    let result = beam """ "c1": { "node": "n1", "dof": [] } """
+
+    Assert.False(result.IsValid)
+
+  [<Fact>]
+  let ``Beam on a single roller is a mechanism`` () =
+    let result = beam """ "c1": { "node": "n1", "dof": ["Uz"] } """
+
+    let expected =
+      [| "Nodes n1, n2 form a mechanism free to move in "
+         + "n1 Ux, n1 Ry, n2 Ux, n2 Uz, n2 Ry" |]
+
+    Assert.Equal<string[]>(expected, result.Errors)
+
+  [<Fact>]
+  let ``Beam on a pin and a roller is stable`` () =
+    let result =
+      beam
+        """ "c1": { "node": "n1", "dof": ["Ux", "Uz"] },
+            "c2": { "node": "n2", "dof": ["Uz"] } """
+
+    Assert.True(result.IsValid)
+
+  let private square diagonal =
+    diagnose
+      $"""{{ "info": {{ "version": "1.0" }},
+             "nodes": {{
+               "n1": {{ "x": 0, "z": 0 }}, "n2": {{ "x": 4, "z": 0 }},
+               "n3": {{ "x": 4, "z": 3 }}, "n4": {{ "x": 0, "z": 3 }} }},
+             "elements": {{
+               "e1": {{ "type": "Truss2D", "nodes": ["n1", "n2"] }},
+               "e2": {{ "type": "Truss2D", "nodes": ["n2", "n3"] }},
+               "e3": {{ "type": "Truss2D", "nodes": ["n3", "n4"] }},
+               "e4": {{ "type": "Truss2D", "nodes": ["n4", "n1"] }}
+               {diagonal} }},
+             "constraints": {{
+               "c1": {{ "node": "n1", "dof": ["Ux", "Uy", "Uz"] }},
+               "c2": {{ "node": "n2", "dof": ["Uy", "Uz"] }} }} }}"""
+
+  [<Fact>]
+  let ``Pin-jointed square without a diagonal is a mechanism`` () =
+    let result = square ""
+
+    let expected =
+      [| "Nodes n1, n2, n3, n4 form a mechanism free to move in n3 Ux, n4 Ux" |]
+
+    Assert.Equal<string[]>(expected, result.Errors)
+
+  [<Fact>]
+  let ``Pin-jointed square with a diagonal is stable`` () =
+    let result =
+      square """, "e5": { "type": "Truss2D", "nodes": ["n1", "n3"] }"""
+
+    Assert.True(result.IsValid)
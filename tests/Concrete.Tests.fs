@@ -0,0 +1,164 @@
+namespace Gazelle.Concrete.Tests
+
+open Xunit
+open Gazelle.Units
+open Gazelle.Design
+open Gazelle.Concrete
+
+module EC2Tests =
+
+  let private beam: RCBeam =
+    { Name = "B1"
+      Section = Rectangular(300.0<mm>, 600.0<mm>)
+      Fck = 30.0<MPa>
+      Fyk = 500.0<MPa>
+      Cover = 30.0<mm>
+      Span = 6000.0<mm>
+      SpanType = SimplySupported
+      Reinforcement =
+        { Tension = 1963.5<mm^2>
+          Compression = 0.0<mm^2>
+          BarDiameter = 25.0<mm>
+          LinkDiameter = 10.0<mm>
+          Links = 0.785<mm^2 / mm> } }
+
+  let private forces =
+    { Moment = 300.0<kNm>
+      Shear = 250.0<kN>
+      ServiceMoment = 200.0<kNm> }
+
+  [<Fact>]
+  let ``300x600 C30 beam under 300kNm requires 1416mm^2 of tension steel`` () =
+    let actual = EC2.Flexure.design beam 300.0<kNm>
+    Assert.Equal(1416.37, RemoveUnits.fromFloat actual.TensionSteel, 1)
+    Assert.Equal(0.0, RemoveUnits.fromFloat actual.CompressionSteel, 6)
+
+  [<Fact>]
+  let ``300x600 C30 beam under 600kNm requires compression steel`` () =
+    let actual = EC2.Flexure.design beam 600.0<kNm>
+    Assert.True(actual.CompressionSteel > 0.0<mm^2>)
+
+  [<Fact>]
+  let ``300x600 C30 beam has concrete shear resistance of 104kN`` () =
+    let actual = EC2.Shear.concreteResistance beam
+    Assert.Equal(104.28, RemoveUnits.fromFloat actual, 1)
+
+  [<Fact>]
+  let ``300x600 C30 beam under 250kN requires links at cot θ of 2.5`` () =
+    let actual = EC2.Shear.design beam 250.0<kN>
+    Assert.Equal(2.5, actual.CotTheta, 6)
+    Assert.Equal(0.4668, RemoveUnits.fromFloat actual.Links, 3)
+
+  [<Fact>]
+  let ``300x600 C30 beam under 200kNm has crack width of 0.196mm`` () =
+    let actual = EC2.Serviceability.crackWidth beam 200.0<kNm>
+    Assert.Equal(0.1965, RemoveUnits.fromFloat actual, 3)
+
+  [<Fact>]
+  let ``Adequately reinforced beam passes all checks`` () =
+    match EC2.designCode.Check beam forces with
+    | Ok report -> Assert.Equal(Pass, report.Status)
+    | Error e -> Assert.Fail(DesignError.getAsString e)
+
+  [<Fact>]
+  let ``Under-reinforced beam fails bending check`` () =
+    let reinforcement = { beam.Reinforcement with Tension = 982.0<mm^2> }
+    let beam = { beam with Reinforcement = reinforcement }
+
+    match EC2.check beam forces with
+    | Ok report -> Assert.Equal(Fail, report.Status)
+    | Error e -> Assert.Fail(DesignError.getAsString e)
+
+module MomentCurvatureTests =
+
+  let private section (tension: float<mm^2>) : FibreSection =
+    { Section = Rectangular(300.0<mm>, 600.0<mm>)
+      Fck = 30.0<MPa>
+      Fyk = 500.0<MPa>
+      Layers = [ { Depth = 547.5<mm>; Area = tension } ] }
+
+  let private analyse (s: FibreSection) =
+    match MomentCurvature.analyse s with
+    | Ok result -> result
+    | Error e -> failwith (DesignError.getAsString e)
+
+  [<Fact>]
+  let ``300x600 C30 section with 1963mm^2 cracks at 58.7kNm`` () =
+    let actual = analyse (section 1963.5<mm^2>)
+    Assert.Equal(58.7, RemoveUnits.fromFloat actual.Cracking.Moment, 1)
+
+  [<Fact>]
+  let ``300x600 C30 section with 1963mm^2 yields before crushing`` () =
+    let actual = analyse (section 1963.5<mm^2>)
+
+    match actual.Yield with
+    | Some y ->
+      Assert.Equal(467.6, RemoveUnits.fromFloat y.Moment, 1)
+      Assert.True(y.Curvature < actual.Ultimate.Curvature)
+    | None -> failwith "Expected tension steel to yield"
+
+  [<Fact>]
+  let ``Fibre ultimate moment agrees with the stress block`` () =
+    let s = section 1963.5<mm^2>
+    let fibre = (analyse s).Ultimate.Moment |> RemoveUnits.fromFloat
+
+    match MomentCurvature.stressBlockMoment s with
+    | Ok block ->
+      Assert.Equal(483.96, RemoveUnits.fromFloat block, 1)
+      Assert.InRange(fibre / RemoveUnits.fromFloat block, 0.99, 1.01)
+    | Error e -> failwith (DesignError.getAsString e)
+
+  [<Fact>]
+  let ``Over-reinforced section crushes before yield`` () =
+    let actual = analyse (section 8000.0<mm^2>)
+    Assert.True(actual.Yield.IsNone)
+
+  [<Fact>]
+  let ``Moment-curvature points start at the origin in curvature order`` () =
+    let actual = analyse (section 1963.5<mm^2>)
+    let curvatures = actual.Points |> List.map (fun p -> p.Curvature)
+    Assert.Equal(0.0<1 / mm>, List.head curvatures)
+    Assert.Equal<float<1 / mm> list>(List.sort curvatures, curvatures)
+
+  [<Fact>]
+  let ``Section without tension reinforcement is invalid`` () =
+    let s =
+      { section 1963.5<mm^2> with
+          Layers = [ { Depth = 50.0<mm>; Area = 1963.5<mm^2> } ] }
+
+    Assert.True(Result.isError (MomentCurvature.analyse s))
+
+module CylinderStrengthTests =
+
+  let private mpa (x: float<MPa>) = RemoveUnits.fromFloat x
+
+  [<Fact>]
+  let ``4000 psi ACI concrete has f'c of 27.6MPa`` () =
+    Assert.Equal(27.579, mpa (CylinderStrength.fck (Aci 4000.0<psi>)), 3)
+
+  [<Fact>]
+  let ``ACI required average strength follows Table 26.12.3.1(b)`` () =
+    let fcm (fc: float<psi>) = mpa (CylinderStrength.fcm (Aci fc))
+    Assert.Equal(24.237, fcm 2500.0<psi>, 3)
+    Assert.Equal(35.879, fcm 4000.0<psi>, 3)
+    Assert.Equal(50.505, fcm 6000.0<psi>, 3)
+
+  [<Fact>]
+  let ``ACI tensile strength and modulus scale with root f'c`` () =
+    let s = Aci 4000.0<psi>
+    Assert.Equal(2.941, mpa (CylinderStrength.fctm s), 3)
+    Assert.Equal(24682.4, mpa (CylinderStrength.ecm s), 1)
+    Assert.Equal(0.003, CylinderStrength.ultimateStrain s, 6)
+
+  [<Fact>]
+  let ``Custom strength uses the EN 1992-1-1 expressions`` () =
+    let s = Custom 30.0<MPa>
+    Assert.Equal(38.0, mpa (CylinderStrength.fcm s), 6)
+    Assert.Equal(2.896, mpa (CylinderStrength.fctm s), 3)
+    Assert.Equal(32836.6, mpa (CylinderStrength.ecm s), 1)
+    Assert.Equal(0.0035, CylinderStrength.ultimateStrain s, 6)
+
+  [<Fact>]
+  let ``High strength custom concrete has a reduced ultimate strain`` () =
+    let actual = CylinderStrength.ultimateStrain (Custom 70.0<MPa>)
+    Assert.Equal(0.002656, actual, 6)
@@ -487,3 +487,60 @@ module GeometryTests =
 //                     200.0<mm>
 //                     0.0<mm>
 //                 |> ignore)
+
+module SectionTests =
+
+  open Gazelle.Units
+  open Gazelle.Geometry
+
+  let private properties (s: Section) =
+    match Section.properties s with
+    | Ok p -> p
+    | Error e -> failwith (SectionGeometryError.getAsString e)
+
+  [<Fact>]
+  let ``300x600 Rectangle has Iy of 5.4e9mm^4 and Wpl,y of 27e6mm^3`` () =
+    let p = properties (Rectangle(300.0<mm>, 600.0<mm>))
+    Assert.Equal("Rectangle 300x600", p.Designation)
+    Assert.Equal(180_000.0, RemoveUnits.fromFloat p.Area, 6)
+    Assert.Equal(5.4e9, RemoveUnits.fromFloat p.SecondMomentY, 0)
+    Assert.Equal(27e6, RemoveUnits.fromFloat p.PlasticModulusY, 0)
+
+  [<Fact>]
+  let ``100mm Circle has I of πd^4/64`` () =
+    let p = properties (Circle 100.0<mm>)
+    let expected = System.Math.PI * 100.0 ** 4.0 / 64.0
+    Assert.Equal(expected, RemoveUnits.fromFloat p.SecondMomentZ, 6)
+    Assert.Equal(2.0 * expected, RemoveUnits.fromFloat p.TorsionConstant, 6)
+
+  [<Fact>]
+  let ``400x200 I section has area of 11600mm^2`` () =
+    let p = properties (IBeam(400.0<mm>, 200.0<mm>, 10.0<mm>, 20.0<mm>))
+    Assert.Equal(11_600.0, RemoveUnits.fromFloat p.Area, 6)
+    Assert.Equal(327_946_666.667, RemoveUnits.fromFloat p.SecondMomentY, 2)
+
+  [<Fact>]
+  let ``300x200 Tee has plastic neutral axis in flange`` () =
+    let p = properties (Tee(300.0<mm>, 200.0<mm>, 10.0<mm>, 20.0<mm>))
+    Assert.Equal(6800.0, RemoveUnits.fromFloat p.Area, 6)
+    Assert.Equal(55_485_490.196, RemoveUnits.fromFloat p.SecondMomentY, 2)
+    Assert.Equal(430_200.0, RemoveUnits.fromFloat p.PlasticModulusY, 6)
+
+  [<Fact>]
+  let ``200x100x10 Box has Bredt torsion constant`` () =
+    let p = properties (Box(200.0<mm>, 100.0<mm>, 10.0<mm>))
+    let expected = 2.0 * 10.0 * 90.0 ** 2.0 * 190.0 ** 2.0 / 290.0
+    Assert.Equal(expected, RemoveUnits.fromFloat p.TorsionConstant, 6)
+
+  [<Fact>]
+  let ``100x5 Pipe has area of 1492mm^2`` () =
+    let p = properties (Pipe(100.0<mm>, 5.0<mm>))
+    let expected = System.Math.PI * (100.0 ** 2.0 - 90.0 ** 2.0) / 4.0
+    Assert.Equal(expected, RemoveUnits.fromFloat p.Area, 6)
+
+  [<Fact>]
+  let ``Pipe with wall thicker than radius is invalid`` () =
+    let expected: Result<SectionProperties, SectionGeometryError> =
+      Error(InvalidDimensions "Walls do not fit within section")
+
+    Assert.Equal(expected, Section.properties (Pipe(100.0<mm>, 60.0<mm>)))
@@ -0,0 +1,151 @@
+namespace Gazelle.IO.Tests
+
+open Xunit
+open Gazelle.IO
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+module GroundMotionTests =
+
+  let private at2 =
+    String.concat
+      "\n"
+      [ "PEER NGA STRONG MOTION DATABASE RECORD"
+        "Imperial Valley-06, 10/15/1979, Test Station, 230"
+        "ACCELERATION TIME SERIES IN UNITS OF G"
+        "NPTS=     5, DT=   .0100 SEC"
+        "  0.1000000E+00  0.2000000E+00  0.1000000E+00  0.0000000E+00"
+        " -0.1000000E+00" ]
+
+  let private values (gm: GroundMotion) =
+    gm.Accelerations |> List.map float
+
+  [<Fact>]
+  let ``AT2 record is read in m/s^2 at its time step`` () =
+    match GroundMotion.parseAT2 "IV" at2 with
+    | Ok gm ->
+      Assert.Equal(0.01<s>, gm.TimeStep)
+      Assert.Equal(5, gm.Accelerations.Length)
+      Assert.Equal(1.961330, (values gm)[1], 6)
+    | Error e -> failwith (IOError.getAsString e)
+
+  [<Fact>]
+  let ``AT2 record with the older header style is read`` () =
+    let text = at2.Replace("NPTS=     5, DT=   .0100 SEC", "5 0.0100 NPTS, DT")
+
+    match GroundMotion.parseAT2 "IV" text with
+    | Ok gm -> Assert.Equal(0.01<s>, gm.TimeStep)
+    | Error e -> failwith (IOError.getAsString e)
+
+  [<Fact>]
+  let ``AT2 record with missing points is invalid`` () =
+    let text = at2.Replace("NPTS=     5", "NPTS=     6")
+    Assert.True(Result.isError (GroundMotion.parseAT2 "IV" text))
+
+  [<Fact>]
+  let ``CSV record skips its header and infers the time step`` () =
+    let text = "time,acc\n0.0,0.0\n0.02,0.1\n0.04,0.0"
+
+    match GroundMotion.parseCsv "record" text with
+    | Ok gm ->
+      Assert.Equal(0.02, float gm.TimeStep, 9)
+      Assert.Equal(3, gm.Accelerations.Length)
+    | Error e -> failwith (IOError.getAsString e)
+
+  [<Fact>]
+  let ``CSV record ignores empty trailing fields`` () =
+    let text = "time,acc,,\n0.0, 0.0,\n0.02,0.1,,\n0.04,0.0\n,,"
+
+    match GroundMotion.parseCsv "record" text with
+    | Ok gm ->
+      Assert.Equal(0.02, float gm.TimeStep, 9)
+      Assert.Equal(3, gm.Accelerations.Length)
+    | Error e -> failwith (IOError.getAsString e)
+
+  [<Fact>]
+  let ``CSV record with a missing acceleration names the row`` () =
+    let text = "time,acc\n0.0,0.0\n0.02,\n0.04,0.0"
+
+    match GroundMotion.parseCsv "record" text with
+    | Ok _ -> failwith "Expected an error"
+    | Error e -> Assert.Contains("'0.02,'", IOError.getAsString e)
+
+  [<Fact>]
+  let ``CSV record with a varying time step is invalid`` () =
+    let text = "0.0,0.0\n0.02,0.1\n0.05,0.0"
+    Assert.True(Result.isError (GroundMotion.parseCsv "record" text))
+
+  [<Fact>]
+  let ``Baseline correction removes a linear drift`` () =
+    let gm: GroundMotion =
+      { Name = "drift"
+        TimeStep = 0.01<s>
+        Accelerations = [ 1.0; 2.0; 3.0; 4.0 ] |> List.map ((*) 1.0<m / s^2>) }
+
+    GroundMotion.baselineCorrect gm
+    |> values
+    |> List.iter (fun a -> Assert.Equal(0.0, a, 9))
+
+  [<Fact>]
+  let ``Resampling to half the time step interpolates linearly`` () =
+    let resampled =
+      GroundMotion.parseAT2 "IV" at2
+      |> Result.bind (GroundMotion.resample 0.005<s>)
+
+    match resampled with
+    | Ok gm ->
+      Assert.Equal(9, gm.Accelerations.Length)
+      Assert.Equal(0.5 * 9.80665 * 0.3, (values gm)[1], 6)
+    | Error e -> failwith (IOError.getAsString e)
+
+module BuildingMassTests =
+
+  let private density = 2500.0<kg / m^3>
+
+  let private storey name (elevation: float<mm>) : Storey =
+    let s: MasterStorey =
+      { Name = Name name
+        Elevation = Elevation elevation
+        Height = Height(PositiveFloat 3000.0<mm>) }
+
+    MasterStorey s
+
+  let private slab name (elevation: float<mm>) (width: float<mm>) : Slab =
+    let corner x y : Point3D = { X = x; Y = y; Z = elevation }
+
+    { Name = Name name
+      Elevation = Elevation elevation
+      Thickness = PositiveFloat 200.0<mm>
+      Vertices =
+        [ corner 0.0<mm> 0.0<mm>
+          corner width 0.0<mm>
+          corner width 5000.0<mm>
+          corner 0.0<mm> 5000.0<mm> ] }
+
+  let private building: Building =
+    { Storeys = [ storey "L1" 3000.0<mm>; storey "L2" 6000.0<mm> ]
+      Slabs =
+        [ slab "S1" 3000.0<mm> 10000.0<mm>
+          slab "S2" 6000.0<mm> 20000.0<mm> ]
+      Columns = [] }
+
+  [<Fact>]
+  let ``Slab mass is area times thickness times density`` () =
+    let mass = Building.slabMass density (slab "S1" 0.0<mm> 10000.0<mm>)
+    Assert.Equal(25000.0, float mass, 6)
+
+  [<Fact>]
+  let ``Centre of mass is weighted towards the heavier slab`` () =
+    let c = Building.centreOfMass density building
+    Assert.Equal(75000.0, float (Building.totalMass density building), 6)
+    Assert.Equal(25000.0 / 3.0, float c.X, 6)
+    Assert.Equal(2500.0, float c.Y, 6)
+    Assert.Equal(5000.0, float c.Z, 6)
+
+  [<Fact>]
+  let ``Storey masses match slabs by elevation`` () =
+    match Building.storeyMasses density building with
+    | [ l1; l2 ] ->
+      Assert.Equal(25000.0, float l1.Mass, 6)
+      Assert.Equal(50000.0, float l2.Mass, 6)
+      Assert.Equal(10000.0, float l2.CentreOfMass.X, 6)
+    | masses -> failwith $"Expected 2 storeys, found {masses.Length}"
@@ -0,0 +1,179 @@
+namespace Gazelle.Loads.Tests
+
+open Xunit
+open Gazelle.Units
+open Gazelle.Design
+open Gazelle.Loads
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+module EN1991Tests =
+
+  [<Fact>]
+  let ``Office floors carry 3kN/m^2 imposed load`` () =
+    let actual = EN1991.Imposed.areaLoad B
+    Assert.Equal(3.0<kPa>, actual.Load)
+    Assert.Equal("Imposed (B)", actual.Label)
+
+  [<Fact>]
+  let ``Domestic floor supporting 50m^2 reduces imposed load by 30%`` () =
+    let actual = EN1991.Imposed.areaReduction A 50.0<m^2>
+    Assert.Equal(0.7, actual, 6)
+
+  [<Fact>]
+  let ``Area reduction for assembly areas is limited to 0.6`` () =
+    let actual = EN1991.Imposed.areaReduction C1 1000.0<m^2>
+    Assert.Equal(0.6, actual, 6)
+
+  [<Fact>]
+  let ``Zone 3 site at 100m has ground snow load of 0.5kN/m^2`` () =
+    let actual = EN1991.Snow.characteristic 3.0 100.0<m>
+    Assert.Equal(0.5, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Roof pitched at 45° has shape coefficient of 0.4`` () =
+    Assert.Equal(0.4, EN1991.Snow.μ1 45.0<deg>, 6)
+
+  [<Fact>]
+  let ``Duopitch roof has undrifted and two drifted cases`` () =
+    let actual = EN1991.Snow.duopitch 0.5<kPa> 30.0<deg> 30.0<deg>
+
+    let loads =
+      actual
+      |> List.map (fun c -> c.Slopes |> List.map (fun s -> s.Load))
+
+    Assert.Equal(3, actual.Length)
+    Assert.Equal<float<kPa> list>([ 0.4<kPa>; 0.4<kPa> ], loads[0])
+    Assert.Equal<float<kPa> list>([ 0.2<kPa>; 0.4<kPa> ], loads[1])
+
+  [<Fact>]
+  let ``Drift against a 2m step is limited to μw of 4.0 over 5m`` () =
+    let actual = EN1991.Snow.drift 0.5<kPa> 10.0<m> 10.0<m> 2.0<m> 5.0<deg>
+    Assert.Equal(Ok(4.0, 5.0<m>), actual)
+
+  [<Fact>]
+  let ``Drift with zero step height is invalid`` () =
+    let actual = EN1991.Snow.drift 0.5<kPa> 10.0<m> 10.0<m> 0.0<m> 5.0<deg>
+    Assert.True(Result.isError actual)
+
+  [<Fact>]
+  let ``Drift against a wall with no upper roof is valid`` () =
+    let actual = EN1991.Snow.drift 0.5<kPa> 0.0<m> 10.0<m> 2.0<m> 5.0<deg>
+    Assert.Equal(Ok(2.5, 5.0<m>), actual)
+
+module EC0Tests =
+
+  let private cases =
+    [ PermanentCase "G"
+      EC0.variable "Q" (Imposed B)
+      EC0.variable "S" Snow ]
+
+  [<Fact>]
+  let ``6.10b combinations lead with each variable case or omit it`` () =
+    let actual = EC0.fundamentalB cases |> List.map (fun c -> c.Factors)
+
+    let expected =
+      [ [ "G", 1.24875 ]
+        [ "G", 1.24875; "S", 1.5 ]
+        [ "G", 1.24875; "Q", 1.5 ]
+        [ "G", 1.24875; "Q", 1.5; "S", 0.75 ]
+        [ "G", 1.24875; "Q", 1.05; "S", 1.5 ] ]
+
+    Assert.Equal(expected.Length, actual.Length)
+
+    List.iter2
+      (fun e a ->
+        List.iter2
+          (fun (en, ef) (an, af) ->
+            Assert.Equal(en, an)
+            Assert.Equal(ef, af, 6))
+          e
+          a)
+      expected
+      actual
+
+  [<Fact>]
+  let ``Quasi-permanent combinations drop zero factors and duplicates`` () =
+    let actual = EC0.quasiPermanent cases |> List.map Combination.describe
+    Assert.Equal<string list>([ "1 G"; "1 G + 0.3 Q" ], actual)
+
+  [<Fact>]
+  let ``Cases of the same action are never combined`` () =
+    let cases =
+      [ PermanentCase "G"
+        EC0.variable "Q" (Imposed B)
+        EC0.variable "W+X" Wind
+        EC0.variable "W-X" Wind ]
+
+    let actual = EC0.fundamental cases
+
+    let both (c: Combination) =
+      let names = c.Factors |> List.map fst
+      List.contains "W+X" names && List.contains "W-X" names
+
+    Assert.Equal(8, actual.Length)
+    Assert.False(actual |> List.exists both)
+
+  [<Fact>]
+  let ``Variable actions may be left out of a combination`` () =
+    let cases =
+      [ PermanentCase "G"
+        EC0.variable "Q" (Imposed B)
+        EC0.variable "W" Wind ]
+
+    let actual = EC0.fundamental cases |> List.map Combination.describe
+    Assert.Contains("1.35 G + 1.5 W", actual)
+
+  [<Fact>]
+  let ``Permanent cases alone give a single combination`` () =
+    let actual = EC0.fundamental [ PermanentCase "G" ]
+    Assert.Equal("ULS 6.10 1", actual.Head.Name)
+    Assert.Equal("1.35 G", Combination.describe actual.Head)
+
+module PatternLoadingTests =
+
+  [<Fact>]
+  let ``Three span beam has five load patterns`` () =
+    let actual = PatternLoading.patterns 3 |> List.map (fun p -> p.Name)
+
+    let expected =
+      [ "All spans"
+        "Odd spans"
+        "Even spans"
+        "Spans 1 and 2"
+        "Spans 2 and 3" ]
+
+    Assert.Equal<string list>(expected, actual)
+
+  [<Fact>]
+  let ``Two equal spans under uniform load have support moment of wL^2/8`` () =
+    let actual =
+      PatternLoading.supportMoments
+        [ 6.0<m>; 6.0<m> ]
+        [ 20.0<kNpm>; 20.0<kNpm> ]
+
+    Assert.Equal(-90.0, RemoveUnits.fromFloat actual[1], 6)
+
+  [<Fact>]
+  let ``Single span envelope is the simply supported response`` () =
+    match PatternLoading.envelope [ 6.0<m> ] 10.0<kNpm> 20.0<kNpm> with
+    | Ok points ->
+      let mid = points |> List.find (fun p -> p.Position = 3.0<m>)
+      Assert.Equal(90.0, RemoveUnits.fromFloat mid.MaxMoment, 6)
+      Assert.Equal(60.0, RemoveUnits.fromFloat points.Head.MaxShear, 6)
+    | Error e -> failwith (DesignError.getAsString e)
+
+  [<Fact>]
+  let ``Two span envelope captures hogging and pattern sagging`` () =
+    match PatternLoading.envelope [ 6.0<m>; 6.0<m> ] 10.0<kNpm> 20.0<kNpm> with
+    | Ok points ->
+      let span = points |> List.filter (fun p -> p.Span = 1)
+      let support = span |> List.find (fun p -> p.Position = 6.0<m>)
+      let sagging = span |> List.map (fun p -> p.MaxMoment) |> List.max
+      Assert.Equal(-90.0, RemoveUnits.fromFloat support.MinMoment, 6)
+      Assert.Equal(59.4, RemoveUnits.fromFloat sagging, 6)
+    | Error e -> failwith (DesignError.getAsString e)
+
+  [<Fact>]
+  let ``Beam without spans is invalid`` () =
+    let actual = PatternLoading.envelope [] 10.0<kNpm> 20.0<kNpm>
+    Assert.True(Result.isError actual)
@@ -0,0 +1,122 @@
+namespace Gazelle.Materials.Tests
+
+open Xunit
+open Gazelle.Units
+open Gazelle.Materials
+
+module OrthotropicTests =
+
+  let private carbon: Orthotropic =
+    { E1 = 181000.0<MPa>
+      E2 = 10300.0<MPa>
+      G12 = 7170.0<MPa>
+      Nu12 = 0.28 }
+
+  let private ply (angle: float<deg>) : Ply =
+    { Material = carbon
+      Thickness = 0.125<mm>
+      Angle = angle }
+
+  [<Fact>]
+  let ``Carbon lamina has reduced stiffness Q11 of 181811MPa`` () =
+    let q = Orthotropic.reducedStiffness carbon
+    Assert.Equal(181811.14, RemoveUnits.fromFloat q[0, 0], 1)
+    Assert.Equal(10346.16, RemoveUnits.fromFloat q[1, 1], 1)
+    Assert.Equal(2896.92, RemoveUnits.fromFloat q[0, 1], 1)
+    Assert.Equal(7170.0, RemoveUnits.fromFloat q[2, 2], 6)
+
+  [<Fact>]
+  let ``Lamina rotated by 90° swaps Q11 and Q22`` () =
+    let q = Orthotropic.reducedStiffness carbon |> Array2D.map float
+
+    let qb =
+      Orthotropic.transformedStiffness carbon 90.0<deg> |> Array2D.map float
+
+    Assert.Equal(q[1, 1], qb[0, 0], 6)
+    Assert.Equal(q[0, 0], qb[1, 1], 6)
+    Assert.Equal(0.0, qb[0, 2], 6)
+
+  [<Fact>]
+  let ``Lamina rotated by 45° has coupled shear terms`` () =
+    let qb =
+      Orthotropic.transformedStiffness carbon 45.0<deg> |> Array2D.map float
+
+    Assert.NotEqual(0.0, qb[0, 2])
+    Assert.Equal(qb[0, 0], qb[1, 1], 6)
+
+  [<Fact>]
+  let ``Lamina with ν12² >= E1/E2 is invalid`` () =
+    let actual = Orthotropic.tryCreate 10000.0<MPa> 40000.0<MPa> 5000.0<MPa> 0.6
+    Assert.True(Result.isError actual)
+
+  [<Fact>]
+  let ``Single 0° ply has A11 equal to Q11 t`` () =
+    match Laminate.tryAbd [ ply 0.0<deg> ] with
+    | Ok abd ->
+      let q = Orthotropic.reducedStiffness carbon
+      let expected = RemoveUnits.fromFloat q[0, 0] * 0.125
+      Assert.Equal(expected, RemoveUnits.fromFloat abd.A[0, 0], 6)
+    | Error e -> failwith (MaterialError.getAsString e)
+
+  [<Fact>]
+  let ``Symmetric layup has no bending-extension coupling`` () =
+    let layup =
+      Laminate.symmetric [ ply 0.0<deg>; ply 45.0<deg>; ply 90.0<deg> ]
+
+    match Laminate.tryAbd layup with
+    | Ok abd ->
+      abd.B
+      |> Array2D.iter (fun b -> Assert.Equal(0.0, RemoveUnits.fromFloat b, 6))
+    | Error e -> failwith (MaterialError.getAsString e)
+
+  [<Fact>]
+  let ``Unsymmetric [0/90] cross-ply has B11 of -1339.6N`` () =
+    match Laminate.tryAbd [ ply 0.0<deg>; ply 90.0<deg> ] with
+    | Ok abd -> Assert.Equal(-1339.57, RemoveUnits.fromFloat abd.B[0, 0], 1)
+    | Error e -> failwith (MaterialError.getAsString e)
+
+  [<Fact>]
+  let ``Laminate with no plies is invalid`` () =
+    Assert.True(Result.isError (Laminate.tryAbd []))
+
+module ReinforcementTests =
+
+  [<Fact>]
+  let ``B500B has yield strain of 0.0025`` () =
+    let actual = Reinforcement.ofGrade B500B |> Reinforcement.yieldStrain
+    Assert.Equal(0.0025, actual, 6)
+
+  [<Fact>]
+  let ``B500C has minimum tensile strength of 575MPa`` () =
+    let actual = Reinforcement.ofGrade B500C |> Reinforcement.tensileStrength
+    Assert.Equal(575.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``B500B has design yield strength of 434.8MPa`` () =
+    let actual =
+      Reinforcement.ofGrade B500B |> Reinforcement.designStrength 1.15
+
+    Assert.Equal(434.78, RemoveUnits.fromFloat actual, 2)
+
+  [<Fact>]
+  let ``B500A keeps its grade and ductility class`` () =
+    let actual = Reinforcement.ofGrade B500A
+    Assert.Equal(B500A, actual.Grade)
+    Assert.Equal(ClassA, actual.Ductility)
+
+  [<Fact>]
+  let ``Standard strand has low relaxation`` () =
+    let actual = PrestressingStrand.y1860S7_15_2.Relaxation
+    Assert.Equal(Class2Relaxation, actual)
+
+  [<Fact>]
+  let ``Y1860S7 15.7 strand has breaking load of 279kN`` () =
+    let actual = PrestressingStrand.breakingLoad PrestressingStrand.y1860S7_15_7
+    Assert.Equal(279.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Class 2 strand at 0.7fpk loses 3.9% after 500_000 hours`` () =
+    let strand = PrestressingStrand.y1860S7_15_7
+    let σpi = 0.7 * strand.Fpk
+    let actual = PrestressingStrand.relaxationLoss strand σpi 500_000.0
+    Assert.Equal(0.03901, actual / σpi, 4)
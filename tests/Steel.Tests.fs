@@ -0,0 +1,213 @@
+namespace Gazelle.Steel.Tests
+
+open Xunit
+open Gazelle.Units
+open Gazelle.Design
+open Gazelle.Geometry
+open Gazelle.Steel
+
+module EC3Tests =
+
+  let private s275 = SteelMaterial.create 275.0<MPa> 410.0<MPa>
+
+  let private ub457x191x67: ISection =
+    { Designation = "UB 457x191x67"
+      Depth = 453.4<mm>
+      Width = 189.9<mm>
+      WebThickness = 8.5<mm>
+      FlangeThickness = 12.7<mm>
+      RootRadius = 10.2<mm>
+      Area = 8550.0<mm^2>
+      SecondMomentY = 294_000_000.0<mm^4>
+      SecondMomentZ = 14_500_000.0<mm^4>
+      ElasticModulusY = 1_300_000.0<mm^3>
+      ElasticModulusZ = 153_000.0<mm^3>
+      PlasticModulusY = 1_470_000.0<mm^3>
+      PlasticModulusZ = 237_000.0<mm^3>
+      TorsionConstant = 371_000.0<mm^4>
+      WarpingConstant = 705_000_000_000.0<mm^6> }
+
+  let private uc254x254x73: ISection =
+    { Designation = "UC 254x254x73"
+      Depth = 254.1<mm>
+      Width = 254.6<mm>
+      WebThickness = 8.6<mm>
+      FlangeThickness = 14.2<mm>
+      RootRadius = 12.7<mm>
+      Area = 9310.0<mm^2>
+      SecondMomentY = 114_000_000.0<mm^4>
+      SecondMomentZ = 39_100_000.0<mm^4>
+      ElasticModulusY = 898_000.0<mm^3>
+      ElasticModulusZ = 307_000.0<mm^3>
+      PlasticModulusY = 992_000.0<mm^3>
+      PlasticModulusZ = 465_000.0<mm^3>
+      TorsionConstant = 576_000.0<mm^4>
+      WarpingConstant = 562_000_000_000.0<mm^6> }
+
+  let private createMember section length : SteelMember =
+    { Name = "M1"
+      Section = section
+      Material = s275
+      BucklingLengths =
+        { MajorAxis = length
+          MinorAxis = length
+          LateralTorsional = length }
+      C1 = 1.0 }
+
+  let private forces axial moment =
+    { Axial = axial
+      ShearZ = 0.0<kN>
+      MomentY = moment
+      MomentZ = 0.0<kNm> }
+
+  let private utilisation name (report: DesignReport) =
+    let check = report.Checks |> List.find (fun c -> c.Name = name)
+    check.Utilisation
+
+  [<Fact>]
+  let ``UB 457x191x67 in S275 under bending only is Class 1`` () =
+    let actual = EC3.Classification.section ub457x191x67 275.0<MPa> 0.0<kN>
+    Assert.Equal(Class1, actual)
+
+  [<Fact>]
+  let ``UB 457x191x67 in S275 has major axis bending resistance of 404kNm`` () =
+    let actual = EC3.Resistance.bendingY Class1 ub457x191x67 s275
+    Assert.Equal(404.25, RemoveUnits.fromFloat actual, 2)
+
+  [<Fact>]
+  let ``UC 254x254x73 in S275 over 4m has Nb,z,Rd of 1838kN`` () =
+    let _, actual =
+      EC3.Buckling.flexural
+        uc254x254x73
+        s275
+        CurveC
+        uc254x254x73.SecondMomentZ
+        4000.0<mm>
+
+    Assert.Equal(1837.96, RemoveUnits.fromFloat actual, 1)
+
+  [<Fact>]
+  let ``UC 254x254x73 column under 1000kN over 4m passes all checks`` () =
+    let m = createMember uc254x254x73 4000.0<mm>
+
+    match EC3.check m (forces 1000.0<kN> 0.0<kNm>) with
+    | Ok report ->
+      Assert.Equal(Pass, report.Status)
+
+      Assert.Equal(
+        1000.0 / 1837.96,
+        utilisation "Flexural Buckling Z-Z" report,
+        3
+      )
+    | Error e -> Assert.Fail(DesignError.getAsString e)
+
+  [<Fact>]
+  let ``UB 457x191x67 in tension of 3000kN fails`` () =
+    let m = createMember ub457x191x67 1000.0<mm>
+
+    match EC3.designCode.Check m (forces (-3000.0<kN>) 0.0<kNm>) with
+    | Ok report -> Assert.Equal(Fail, report.Status)
+    | Error e -> Assert.Fail(DesignError.getAsString e)
+
+  [<Fact>]
+  let ``Member with zero buckling length is invalid`` () =
+    let m = createMember ub457x191x67 0.0<mm>
+
+    match EC3.check m (forces 0.0<kN> 100.0<kNm>) with
+    | Ok _ -> Assert.Fail("Expected invalid input.")
+    | Error e -> Assert.Equal(InvalidInput "Buckling length <= 0", e)
+
+module SectionsTests =
+
+  [<Fact>]
+  let ``UB 406x178x67 has area of 8550mm^2`` () =
+    match Sections.tryFind "UB 406x178x67" with
+    | Ok s ->
+      Assert.Equal("UB 406x178x67", s.Designation)
+      Assert.Equal(8550.0, RemoveUnits.fromFloat s.Area, 6)
+    | Error e -> Assert.Fail(SectionError.getAsString e)
+
+  [<Fact>]
+  let ``SHS 100x100x5.0 has equal second moments about both axes`` () =
+    match Sections.tryFind "SHS 100x100x5.0" with
+    | Ok s -> Assert.Equal(s.SecondMomentY, s.SecondMomentZ)
+    | Error e -> Assert.Fail(SectionError.getAsString e)
+
+  [<Fact>]
+  let ``Catalogue UC 254x254x73 matches hand-typed section`` () =
+    match Sections.tryFindISection "uc 254x254x73" with
+    | Ok s ->
+      Assert.Equal("UC 254x254x73", s.Designation)
+      Assert.Equal(9310.0, RemoveUnits.fromFloat s.Area, 6)
+      Assert.Equal(562e9, RemoveUnits.fromFloat s.WarpingConstant, 0)
+    | Error e -> Assert.Fail(SectionError.getAsString e)
+
+  [<Fact>]
+  let ``Hollow section is not an I section`` () =
+    let expected: Result<ISection, SectionError> =
+      Error(NotAnISection "SHS 100x100x5.0")
+
+    Assert.Equal(expected, Sections.tryFindISection "SHS 100x100x5.0")
+
+  [<Fact>]
+  let ``Unknown serial size is invalid`` () =
+    let expected: Result<SectionProperties, SectionError> =
+      Error(UnknownSection "UB 999x999x999")
+
+    Assert.Equal(expected, Sections.tryFind "UB 999x999x999")
+
+  [<Fact>]
+  let ``Unknown section family is invalid`` () =
+    let expected: Result<SectionProperties, SectionError> =
+      Error(UnknownFamily "IPE")
+
+    Assert.Equal(expected, Sections.tryFind "IPE 300")
+
+module SteelGradeTests =
+
+  [<Fact>]
+  let ``S355 up to 16mm has fy of 355MPa`` () =
+    let actual = SteelGrade.tryStrengths S355 16.0<mm>
+    Assert.Equal(Ok(355.0<MPa>, 470.0<MPa>), actual)
+
+  [<Fact>]
+  let ``S355 between 16mm and 40mm has fy of 345MPa`` () =
+    let actual = SteelGrade.tryStrengths S355 20.0<mm>
+    Assert.Equal(Ok(345.0<MPa>, 470.0<MPa>), actual)
+
+  [<Fact>]
+  let ``S275 between 100mm and 150mm has fu of 400MPa`` () =
+    let actual = SteelGrade.tryStrengths S275 120.0<mm>
+    Assert.Equal(Ok(225.0<MPa>, 400.0<MPa>), actual)
+
+  [<Fact>]
+  let ``A992 has fy of 345MPa at any thickness`` () =
+    let actual = SteelGrade.tryStrengths A992 250.0<mm>
+    Assert.Equal(Ok(345.0<MPa>, 450.0<MPa>), actual)
+
+  [<Fact>]
+  let ``S355 thicker than 150mm is out of range`` () =
+    match SteelGrade.tryStrengths S355 200.0<mm> with
+    | Error(ThicknessOutOfRange _) -> ()
+    | _ -> failwith "Expected ThicknessOutOfRange"
+
+  [<Fact>]
+  let ``S460 material has standard elastic properties`` () =
+    match SteelMaterial.ofGrade S460 30.0<mm> with
+    | Ok m ->
+      Assert.Equal(440.0<MPa>, m.YieldStrength)
+      Assert.Equal(210_000.0<MPa>, m.ElasticModulus)
+    | Error e -> failwith (SteelGradeError.getAsString e)
+
+  [<Fact>]
+  let ``A992 material has AISC 360 elastic properties`` () =
+    match SteelMaterial.ofGrade A992 20.0<mm> with
+    | Ok m ->
+      Assert.Equal(200_000.0<MPa>, m.ElasticModulus)
+      Assert.Equal(77_200.0<MPa>, m.ShearModulus)
+    | Error e -> failwith (SteelGradeError.getAsString e)
+
+  [<Fact>]
+  let ``Grade names parse ignoring case`` () =
+    Assert.Equal(Ok S275, SteelGrade.tryParse "s275")
+    Assert.Equal(Error(UnknownGrade "S999"), SteelGrade.tryParse "S999")
@@ -0,0 +1,192 @@
+namespace Gazelle.Units.Tests
+
+open Xunit
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+open Gazelle.Units
+
+module DeriveTests =
+
+  [<Fact>]
+  let ``Force of 10kN acting at lever arm of 2m gives moment of 20kNm`` () =
+    let actual: float<kNm> = Derive.moment 10.0<kN> 2.0<m>
+    Assert.Equal(20.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Force of 1000N acting over 100mm^2 gives stress of 10MPa`` () =
+    let (Stress stress) = Derive.stress 1000.0<N> 100.0<mm^2>
+    let actual: float<MPa> = stress
+    Assert.Equal(10.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Force of 50kN acting over 10m^2 gives pressure of 5kPa`` () =
+    let (Stress pressure) = Derive.pressure 50.0<kN> 10.0<m^2>
+    let actual: float<kPa> = pressure
+    Assert.Equal(5.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Stress of 20MPa acting over 500mm^2 gives force of 10_000N`` () =
+    let actual: float<N> = Derive.force (Stress 20.0<MPa>) 500.0<mm^2>
+    Assert.Equal(10_000.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Mass of 4800kg occupying 2m^3 gives density of 2400kg/m^3`` () =
+    let (Density density) = Derive.density 4800.0<kg> 2.0<m^3>
+    Assert.Equal(2400.0, RemoveUnits.fromFloat density, 6)
+
+module ConversionTests =
+
+  [<Fact>]
+  let ``Area of 250_000mm^2 gives 0.25m^2`` () =
+    let actual = Convert.squareMillimetresToSquareMetres 250_000.0<mm^2>
+    Assert.Equal(0.25, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Stress of 0.5MPa gives 500kPa`` () =
+    let actual = Convert.megapascalsToKilopascals 0.5<MPa>
+    Assert.Equal(500.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Elastic modulus of 210GPa gives 210_000MPa`` () =
+    let actual = Convert.gigapascalsToMegapascals 210.0<GPa>
+    Assert.Equal(210_000.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Concrete strength of 4000psi gives 27.58MPa`` () =
+    let actual = Convert.psiToMegapascals 4000.0<psi>
+    Assert.Equal(27.579, RemoveUnits.fromFloat actual, 3)
+
+  [<Fact>]
+  let ``Line load of 12kN/m gives 12N/mm`` () =
+    let actual = Convert.kilonewtonsPerMetreToNewtonsPerMillimetre 12.0<kNpm>
+    Assert.Equal(12.0, RemoveUnits.fromFloat actual, 6)
+
+  [<Fact>]
+  let ``Moment derived from kN and m converts to Nmm`` () =
+    let moment: float<kNm> = Derive.moment 10.0<kN> 2.0<m>
+    let actual = Convert.kilonewtonMetresToNewtonMillimetres moment
+    Assert.Equal(20_000_000.0, RemoveUnits.fromFloat actual, 6)
+
+module QuantityTests =
+
+  [<Fact>]
+  let ``Parsing 4.5 kN gives 4.5 Kilonewtons`` () =
+    let expected: Result<Quantity, UnitError> =
+      Ok { Value = 4.5; Unit = UnitOfMeasure.Kilonewton }
+
+    Assert.Equal(expected, Quantity.tryParse "4.5 kN")
+
+  [<Fact>]
+  let ``Parsing 35MPa without whitespace gives 35 Megapascals`` () =
+    let expected: Result<Quantity, UnitError> =
+      Ok { Value = 35.0; Unit = UnitOfMeasure.Megapascal }
+
+    Assert.Equal(expected, Quantity.tryParse "35MPa")
+
+  [<Fact>]
+  let ``Parsing quantity with unknown unit is invalid`` () =
+    let expected: Result<Quantity, UnitError> = Error(UnknownUnit "furlong")
+    Assert.Equal(expected, Quantity.tryParse "12 furlong")
+
+  [<Fact>]
+  let ``Parsing quantity without a number is invalid`` () =
+    let expected: Result<Quantity, UnitError> = Error(MalformedQuantity "kN")
+    Assert.Equal(expected, Quantity.tryParse "kN")
+
+  [<Fact>]
+  let ``Displaying 12 Feet gives 12 ft`` () =
+    let q = Quantity.create UnitOfMeasure.Foot 12.0
+    Assert.Equal("12 ft", Quantity.toString q)
+
+  [<Fact>]
+  let ``Parsing 12 ft as Millimetres gives 3657.6mm`` () =
+    match Quantity.tryParseAs UnitOfMeasure.Millimetre "12 ft" with
+    | Ok actual -> Assert.Equal(3657.6, actual, 6)
+    | Error e -> Assert.Fail(UnitError.getAsString e)
+
+  [<Fact>]
+  let ``Converting Kilonewtons to Millimetres is invalid`` () =
+    let q = Quantity.create UnitOfMeasure.Kilonewton 1.0
+
+    let expected: Result<Quantity, UnitError> =
+      Error(
+        IncompatibleUnits(UnitOfMeasure.Kilonewton, UnitOfMeasure.Millimetre)
+      )
+
+    Assert.Equal(expected, Quantity.tryConvert UnitOfMeasure.Millimetre q)
+
+  [<Fact>]
+  let ``Converting 20 Degrees Celsius to Kelvin gives 293.15K`` () =
+    let q = Quantity.create UnitOfMeasure.DegreeCelsius 20.0
+
+    match Quantity.tryConvert UnitOfMeasure.Kelvin q with
+    | Ok actual -> Assert.Equal(293.15, actual.Value, 6)
+    | Error e -> Assert.Fail(UnitError.getAsString e)
+
+  [<Fact>]
+  let ``Parsing 180 deg as Radians gives π rad`` () =
+    match Quantity.tryParseAs UnitOfMeasure.Radian "180 deg" with
+    | Ok actual -> Assert.Equal(System.Math.PI, actual, 6)
+    | Error e -> Assert.Fail(UnitError.getAsString e)
+
+  [<Fact>]
+  let ``Parsing 5 kN/m as Newtons per Millimetre gives 5 N/mm`` () =
+    match Quantity.tryParseAs UnitOfMeasure.NewtonPerMillimetre "5 kN/m" with
+    | Ok actual -> Assert.Equal(5.0, actual, 6)
+    | Error e -> Assert.Fail(UnitError.getAsString e)
+
+module SerializationTests =
+
+  open System.Text.Json
+
+  let private options (target: UnitOfMeasure option) =
+    let options = JsonSerializerOptions()
+    options.Converters.Add(QuantityJsonConverter(target))
+    options
+
+  [<Fact>]
+  let ``Quantity of 35 MPa round-trips through JSON`` () =
+    let q = Quantity.create UnitOfMeasure.Megapascal 35.0
+    let json = JsonSerializer.Serialize(q, options None)
+    Assert.Equal("""{"value":35,"unit":"MPa"}""", json)
+    Assert.Equal(q, JsonSerializer.Deserialize<Quantity>(json, options None))
+
+  [<Fact>]
+  let ``Quantity of 1500 mm is converted to 1.5 m on read`` () =
+    let json = """{ "value": 1500.0, "unit": "mm" }"""
+
+    let actual =
+      JsonSerializer.Deserialize<Quantity>(
+        json,
+        options (Some UnitOfMeasure.Metre)
+      )
+
+    Assert.Equal(UnitOfMeasure.Metre, actual.Unit)
+    Assert.Equal(1.5, actual.Value, 6)
+
+  [<Fact>]
+  let ``Quantity with unknown unit is rejected on read`` () =
+    let json = """{ "value": 1.0, "unit": "furlong" }"""
+
+    Assert.Throws<JsonException>(fun () ->
+      JsonSerializer.Deserialize<Quantity>(json, options None) |> ignore)
+    |> ignore
+
+  [<Fact>]
+  let ``Quantity with a non-numeric value is rejected on read`` () =
+    let json = """{ "value": "35", "unit": "MPa" }"""
+
+    Assert.Throws<JsonException>(fun () ->
+      JsonSerializer.Deserialize<Quantity>(json, options None) |> ignore)
+    |> ignore
+
+  [<Fact>]
+  let ``Quantity of incompatible dimension is rejected on read`` () =
+    let json = """{ "value": 1.0, "unit": "kN" }"""
+
+    Assert.Throws<JsonException>(fun () ->
+      JsonSerializer.Deserialize<Quantity>(
+        json,
+        options (Some UnitOfMeasure.Metre)
+      )
+      |> ignore)
+    |> ignore
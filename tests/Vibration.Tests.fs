@@ -0,0 +1,59 @@
+namespace Gazelle.Vibration.Tests
+
+open Xunit
+open Gazelle.Vibration
+open FSharp.Data.UnitSystems.SI.UnitSymbols
+
+module FootfallTests =
+
+  let private mode frequency mass amplitude : FloorMode =
+    { Frequency = frequency
+      ModalMass = mass
+      Damping = 0.03
+      Excitation = amplitude
+      Response = amplitude }
+
+  let private floor = [ mode 4.0<Hz> 10000.0<kg> 1.0 ]
+
+  [<Fact>]
+  let ``Frequency weighting rises linearly from 2Hz to 5Hz`` () =
+    Assert.Equal(0.4, Footfall.weighting 1.5<Hz>, 6)
+    Assert.Equal(0.8, Footfall.weighting 4.0<Hz>, 6)
+    Assert.Equal(1.0, Footfall.weighting 10.0<Hz>, 6)
+
+  [<Fact>]
+  let ``Second harmonic resonance of a 4Hz floor`` () =
+    let actual = Footfall.resonantResponse 10.0<m> 2.0<Hz> floor
+    Assert.Equal(12.724, actual.ResponseFactor, 3)
+
+  [<Fact>]
+  let ``Responses of each mode combine with their phase`` () =
+    let modes = floor @ [ mode 6.0<Hz> 20000.0<kg> (sqrt 0.5) ]
+    let actual = Footfall.resonantResponse 10.0<m> 2.0<Hz> modes
+    Assert.Equal(13.180, actual.ResponseFactor, 3)
+
+  [<Fact>]
+  let ``Critical pace frequency excites the floor at resonance`` () =
+    match Footfall.responseFactor 10.0<m> floor with
+    | Ok actual -> Assert.Equal(2.0, float actual.WalkingFrequency, 6)
+    | Error _ -> failwith "Expected a response"
+
+  [<Fact>]
+  let ``Floor without modes is rejected`` () =
+    Assert.True(Result.isError (Footfall.responseFactor 10.0<m> []))
+
+  [<Fact>]
+  let ``Report gives the critical point of each group`` () =
+    let groups: FloorGroup list =
+      [ { Name = "Bay A"
+          Points = [ [ mode 4.0<Hz> 10000.0<kg> 0.5 ]; floor ] }
+        { Name = "Corridor"
+          Points = [ [ mode 4.0<Hz> 10000.0<kg> 0.5 ] ] } ]
+
+    match Footfall.report 10.0<m> groups with
+    | Ok [ bay; corridor ] ->
+      Assert.Equal(2, bay.Point)
+      Assert.Equal(12.724, bay.Response.ResponseFactor, 3)
+      Assert.Equal("Corridor", corridor.Group)
+      Assert.Equal(3.181, corridor.Response.ResponseFactor, 3)
+    | _ -> failwith "Expected a report for each group"